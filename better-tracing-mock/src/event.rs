@@ -43,7 +43,7 @@ use crate::{
 /// the methods and the [`event`] module.
 ///
 /// [`event`]: mod@crate::event
-#[derive(Default, Eq, PartialEq)]
+#[derive(Default)]
 pub struct ExpectedEvent {
     pub(super) fields: Option<field::ExpectedFields>,
     pub(super) ancestry: Option<ExpectedAncestry>,
@@ -549,9 +549,33 @@ impl ExpectedEvent {
             event
         );
         if let Some(ref mut expected_fields) = self.fields {
+            // Collect the actual fields up front, before `checker` consumes
+            // matched entries out of `expected_fields`, so the diagnostic
+            // below can still render the full expected side on failure.
+            let mut recorder = field::RecordingVisitor::default();
+            event.record(&mut recorder);
+            let actual_fields = recorder.into_fields();
+
+            let expected_header = format!(
+                "level={} target={} name={:?}",
+                self.metadata
+                    .level
+                    .map(|level| level.to_string())
+                    .unwrap_or_else(|| "*".to_string()),
+                self.metadata.target.as_deref().unwrap_or("*"),
+                self.metadata.name.as_deref().unwrap_or("*"),
+            );
+            let actual_header = format!(
+                "level={} target={} name={:?}",
+                meta.level(),
+                meta.target(),
+                name,
+            );
+            let diff = expected_fields.logfmt_diff(&expected_header, &actual_header, &actual_fields);
+
             let mut checker = expected_fields.checker(name, subscriber_name);
             event.record(&mut checker);
-            checker.finish();
+            checker.finish(diff);
         }
 
         if let Some(ref expected_ancestry) = self.ancestry {
@@ -96,7 +96,7 @@ use tracing::{
 /// the methods and the [`field`] module.
 ///
 /// [`field`]: mod@crate::field
-#[derive(Default, Debug, Eq, PartialEq)]
+#[derive(Default, Debug)]
 pub struct ExpectedFields {
     fields: HashMap<String, ExpectedValue>,
     only: bool,
@@ -114,42 +114,288 @@ pub struct ExpectedField {
     pub(super) value: ExpectedValue,
 }
 
-#[derive(Debug)]
+/// A value actually recorded by a `tracing` span or event.
+///
+/// This is the type passed to predicates built with
+/// [`ExpectedField::with_value_matching`], [`ExpectedField::with_value_in_range`],
+/// and [`ExpectedField::with_value_containing`]. It is intentionally a
+/// separate type from [`ExpectedValue`]: the expected side of a comparison
+/// may be a predicate rather than a concrete value, so recorded values are
+/// captured on their own rather than being converted into an expectation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedValue {
+    /// A recorded `f64` value.
+    F64(f64),
+    /// A recorded `i64` value.
+    I64(i64),
+    /// A recorded `u64` value.
+    U64(u64),
+    /// A recorded `i128` value.
+    I128(i128),
+    /// A recorded `u128` value.
+    U128(u128),
+    /// A recorded `bool` value.
+    Bool(bool),
+    /// A recorded `&str` value.
+    Str(String),
+    /// A value recorded via its `fmt::Debug` representation.
+    Debug(String),
+    /// A value recorded via `record_error`, rendered as its `Display` text
+    /// followed by the `Display` text of each `source()` in its error chain.
+    Error(String),
+}
+
+impl fmt::Display for RecordedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordedValue::F64(v) => write!(f, "f64 = {:?}", v),
+            RecordedValue::I64(v) => write!(f, "i64 = {:?}", v),
+            RecordedValue::U64(v) => write!(f, "u64 = {:?}", v),
+            RecordedValue::I128(v) => write!(f, "i128 = {:?}", v),
+            RecordedValue::U128(v) => write!(f, "u128 = {:?}", v),
+            RecordedValue::Bool(v) => write!(f, "bool = {:?}", v),
+            RecordedValue::Str(v) => write!(f, "&str = {:?}", v),
+            RecordedValue::Debug(v) => write!(f, "&fmt::Debug = {:?}", v),
+            RecordedValue::Error(v) => write!(f, "&dyn Error = {:?}", v),
+        }
+    }
+}
+
+/// Quotes a `logfmt` value if it's empty or contains whitespace or a quote,
+/// the same convention structured-logging layers use to keep `key=value`
+/// pairs unambiguous to split on whitespace.
+fn logfmt_quote(value: &str) -> String {
+    if value.is_empty() || value.contains(|c: char| c.is_whitespace() || c == '"') {
+        format!("{:?}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+impl RecordedValue {
+    /// Renders this value as the right-hand side of a `logfmt` `key=value`
+    /// pair, unlike the type-annotated [`fmt::Display`] impl used in
+    /// mismatch text.
+    pub(crate) fn logfmt(&self) -> String {
+        match self {
+            RecordedValue::F64(v) => v.to_string(),
+            RecordedValue::I64(v) => v.to_string(),
+            RecordedValue::U64(v) => v.to_string(),
+            RecordedValue::I128(v) => v.to_string(),
+            RecordedValue::U128(v) => v.to_string(),
+            RecordedValue::Bool(v) => v.to_string(),
+            RecordedValue::Str(v) => logfmt_quote(v),
+            RecordedValue::Debug(v) => logfmt_quote(v),
+            RecordedValue::Error(v) => logfmt_quote(v),
+        }
+    }
+}
+
+/// Renders an error and its full `source()` chain for comparison, since
+/// `dyn Error` has no meaningful notion of equality of its own.
+fn render_error(error: &(dyn std::error::Error + 'static)) -> String {
+    let mut rendered = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        rendered.push_str(": ");
+        rendered.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    rendered
+}
+
 pub(crate) enum ExpectedValue {
     F64(f64),
     I64(i64),
     U64(u64),
+    I128(i128),
+    U128(u128),
     Bool(bool),
     Str(String),
     Debug(String),
+    /// A value matched against an error's `Display` text and `source()` chain.
+    Error(String),
     Any,
+    /// A value matched by invoking a predicate against the recorded value,
+    /// rather than by equality. The `String` is a human-readable description
+    /// used in failure messages, since the closure itself can't be printed.
+    Predicate(Box<dyn Fn(&RecordedValue) -> bool + Send + Sync>, String),
+}
+
+impl fmt::Debug for ExpectedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpectedValue::F64(v) => f.debug_tuple("F64").field(v).finish(),
+            ExpectedValue::I64(v) => f.debug_tuple("I64").field(v).finish(),
+            ExpectedValue::U64(v) => f.debug_tuple("U64").field(v).finish(),
+            ExpectedValue::I128(v) => f.debug_tuple("I128").field(v).finish(),
+            ExpectedValue::U128(v) => f.debug_tuple("U128").field(v).finish(),
+            ExpectedValue::Bool(v) => f.debug_tuple("Bool").field(v).finish(),
+            ExpectedValue::Str(v) => f.debug_tuple("Str").field(v).finish(),
+            ExpectedValue::Debug(v) => f.debug_tuple("Debug").field(v).finish(),
+            ExpectedValue::Error(v) => f.debug_tuple("Error").field(v).finish(),
+            ExpectedValue::Any => write!(f, "Any"),
+            ExpectedValue::Predicate(_, description) => {
+                f.debug_tuple("Predicate").field(description).finish()
+            }
+        }
+    }
 }
 
-impl Eq for ExpectedValue {}
+/// A numeric type `tracing` can record, usable with
+/// [`ExpectedField::with_value_in_range`].
+pub trait RangeValue: Copy + PartialOrd + fmt::Debug + 'static {
+    /// Extracts a value of this type from a [`RecordedValue`], if the
+    /// recorded value was actually recorded as this type.
+    fn from_recorded(value: &RecordedValue) -> Option<Self>;
+}
+
+impl RangeValue for i64 {
+    fn from_recorded(value: &RecordedValue) -> Option<Self> {
+        match value {
+            RecordedValue::I64(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl RangeValue for u64 {
+    fn from_recorded(value: &RecordedValue) -> Option<Self> {
+        match value {
+            RecordedValue::U64(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl RangeValue for f64 {
+    fn from_recorded(value: &RecordedValue) -> Option<Self> {
+        match value {
+            RecordedValue::F64(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
 
-impl PartialEq for ExpectedValue {
-    fn eq(&self, other: &Self) -> bool {
-        use ExpectedValue::*;
+impl From<RecordedValue> for ExpectedValue {
+    fn from(value: RecordedValue) -> Self {
+        match value {
+            RecordedValue::F64(v) => ExpectedValue::F64(v),
+            RecordedValue::I64(v) => ExpectedValue::I64(v),
+            RecordedValue::U64(v) => ExpectedValue::U64(v),
+            RecordedValue::I128(v) => ExpectedValue::I128(v),
+            RecordedValue::U128(v) => ExpectedValue::U128(v),
+            RecordedValue::Bool(v) => ExpectedValue::Bool(v),
+            RecordedValue::Str(v) => ExpectedValue::Str(v),
+            RecordedValue::Debug(v) => ExpectedValue::Debug(v),
+            RecordedValue::Error(v) => ExpectedValue::Error(v),
+        }
+    }
+}
 
+/// Compares a stored expectation against an actually-recorded value.
+///
+/// A concrete expectation is compared by equality; a predicate expectation is
+/// satisfied by invoking the closure against the recorded value. Unlike the
+/// previous `PartialEq for ExpectedValue` this can't be a derive, since
+/// closures have no meaningful notion of equality — it's implemented as
+/// `PartialEq<RecordedValue>` instead of the reflexive `PartialEq` so that
+/// asymmetry is visible in the type signature.
+impl PartialEq<RecordedValue> for ExpectedValue {
+    fn eq(&self, other: &RecordedValue) -> bool {
         match (self, other) {
-            (F64(a), F64(b)) => {
+            (ExpectedValue::F64(a), RecordedValue::F64(b)) => {
                 debug_assert!(!a.is_nan());
                 debug_assert!(!b.is_nan());
 
                 a.eq(b)
             }
-            (I64(a), I64(b)) => a.eq(b),
-            (U64(a), U64(b)) => a.eq(b),
-            (Bool(a), Bool(b)) => a.eq(b),
-            (Str(a), Str(b)) => a.eq(b),
-            (Debug(a), Debug(b)) => a.eq(b),
-            (Any, _) => true,
-            (_, Any) => true,
+            (ExpectedValue::I64(a), RecordedValue::I64(b)) => a.eq(b),
+            (ExpectedValue::U64(a), RecordedValue::U64(b)) => a.eq(b),
+            (ExpectedValue::I128(a), RecordedValue::I128(b)) => a.eq(b),
+            (ExpectedValue::U128(a), RecordedValue::U128(b)) => a.eq(b),
+            (ExpectedValue::Bool(a), RecordedValue::Bool(b)) => a.eq(b),
+            (ExpectedValue::Str(a), RecordedValue::Str(b)) => a.eq(b),
+            (ExpectedValue::Debug(a), RecordedValue::Debug(b)) => a.eq(b),
+            (ExpectedValue::Error(a), RecordedValue::Error(b)) => a.eq(b),
+            (ExpectedValue::Any, _) => true,
+            (ExpectedValue::Predicate(predicate, _), other) => predicate(other),
             _ => false,
         }
     }
 }
 
+/// Returns an [`ExpectedField`] that matches the event's reserved `message`
+/// field, which is recorded whenever an event is created via a `tracing`
+/// macro with a format string (e.g. `tracing::info!("{}", value)`).
+///
+/// The provided `message` is compared against the `Debug` representation
+/// `tracing` records for the formatted message, the same representation used
+/// by `expect::field("message").with_value(...)` — this constructor exists so
+/// that common case doesn't require knowing the reserved field name.
+///
+/// # Examples
+///
+/// ```
+/// use better_tracing_mock::{expect, subscriber};
+///
+/// let event = expect::event().with_fields(expect::msg("something happened"));
+///
+/// let (subscriber, handle) = subscriber::mock()
+///     .event(event)
+///     .run_with_handle();
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::info!("something happened");
+/// });
+///
+/// handle.assert_finished();
+/// ```
+///
+/// A different message will cause the test to fail:
+///
+/// ```should_panic
+/// use better_tracing_mock::{expect, subscriber};
+///
+/// let event = expect::event().with_fields(expect::msg("something happened"));
+///
+/// let (subscriber, handle) = subscriber::mock()
+///     .event(event)
+///     .run_with_handle();
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::info!("something else happened");
+/// });
+///
+/// handle.assert_finished();
+/// ```
+///
+/// It chains with `.and`/`.only` just like any other [`ExpectedField`]:
+///
+/// ```
+/// use better_tracing_mock::{expect, subscriber};
+///
+/// let event = expect::event().with_fields(
+///     expect::msg("something happened").and(expect::field("code").with_value(&500)),
+/// );
+///
+/// let (subscriber, handle) = subscriber::mock()
+///     .event(event)
+///     .run_with_handle();
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::info!(code = 500, "something happened");
+/// });
+///
+/// handle.assert_finished();
+/// ```
+pub fn msg(message: impl fmt::Display) -> ExpectedField {
+    ExpectedField {
+        name: "message".to_string(),
+        value: ExpectedValue::Debug(message.to_string()),
+    }
+}
+
 impl ExpectedField {
     /// Sets the value to expect when matching this field.
     ///
@@ -200,6 +446,149 @@ impl ExpectedField {
         }
     }
 
+    /// Sets a predicate to check the value recorded for this field, instead
+    /// of requiring an exact value.
+    ///
+    /// This is useful for assertions that don't pin down one specific value,
+    /// such as "the field is a `u64` greater than zero".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use better_tracing_mock::{expect, field::RecordedValue, subscriber};
+    ///
+    /// let event = expect::event().with_fields(
+    ///     expect::field("count")
+    ///         .with_value_matching(|value| matches!(value, RecordedValue::U64(n) if *n > 0)),
+    /// );
+    ///
+    /// let (subscriber, handle) = subscriber::mock()
+    ///     .event(event)
+    ///     .run_with_handle();
+    ///
+    /// tracing::subscriber::with_default(subscriber, || {
+    ///     tracing::info!(count = 3_u64);
+    /// });
+    ///
+    /// handle.assert_finished();
+    /// ```
+    pub fn with_value_matching<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&RecordedValue) -> bool + Send + Sync + 'static,
+    {
+        self.with_predicate("a value matching a custom predicate", predicate)
+    }
+
+    /// Sets a range to check a numeric field's value against, instead of
+    /// requiring an exact value.
+    ///
+    /// Works with the `i64`, `u64`, and `f64` values `tracing` records, using
+    /// whichever of those types `range`'s bounds are expressed in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use better_tracing_mock::{expect, subscriber};
+    ///
+    /// let event = expect::event().with_fields(expect::field("count").with_value_in_range(1_i64..=10));
+    ///
+    /// let (subscriber, handle) = subscriber::mock()
+    ///     .event(event)
+    ///     .run_with_handle();
+    ///
+    /// tracing::subscriber::with_default(subscriber, || {
+    ///     tracing::info!(count = 5_i64);
+    /// });
+    ///
+    /// handle.assert_finished();
+    /// ```
+    pub fn with_value_in_range<T, R>(self, range: R) -> Self
+    where
+        T: RangeValue,
+        R: std::ops::RangeBounds<T> + fmt::Debug + Send + Sync + 'static,
+    {
+        let description = format!("{} in {:?}", std::any::type_name::<T>(), range);
+        self.with_predicate(description, move |recorded| {
+            T::from_recorded(recorded).is_some_and(|value| range.contains(&value))
+        })
+    }
+
+    /// Sets a substring to check a string or `fmt::Debug`-recorded field's
+    /// value against, instead of requiring an exact value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use better_tracing_mock::{expect, subscriber};
+    ///
+    /// let event = expect::event()
+    ///     .with_fields(expect::field("path").with_value_containing("/users/"));
+    ///
+    /// let (subscriber, handle) = subscriber::mock()
+    ///     .event(event)
+    ///     .run_with_handle();
+    ///
+    /// tracing::subscriber::with_default(subscriber, || {
+    ///     tracing::info!(path = "/users/123/profile");
+    /// });
+    ///
+    /// handle.assert_finished();
+    /// ```
+    pub fn with_value_containing(self, substring: impl Into<String>) -> Self {
+        let substring = substring.into();
+        let description = format!("a value containing {:?}", substring);
+        self.with_predicate(description, move |recorded| match recorded {
+            RecordedValue::Str(s) => s.contains(&substring),
+            RecordedValue::Debug(s) => s.contains(&substring),
+            _ => false,
+        })
+    }
+
+    /// Sets a regular expression to check a string or `fmt::Debug`-recorded
+    /// field's value against, instead of requiring an exact value.
+    ///
+    /// The pattern is matched anywhere in the value; use `^`/`$` anchors to
+    /// require a full match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use better_tracing_mock::{expect, subscriber};
+    ///
+    /// let event = expect::event()
+    ///     .with_fields(expect::field("request_id").with_value_regex("[0-9a-f]{32}"));
+    ///
+    /// let (subscriber, handle) = subscriber::mock()
+    ///     .event(event)
+    ///     .run_with_handle();
+    ///
+    /// tracing::subscriber::with_default(subscriber, || {
+    ///     tracing::info!(request_id = "ba7816bf8f01cfea414140de5dae2223");
+    /// });
+    ///
+    /// handle.assert_finished();
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn with_value_regex(self, pattern: &str) -> Self {
+        let regex = regex::Regex::new(pattern).expect("invalid regex pattern");
+        let description = format!("a value matching /{}/", pattern);
+        self.with_predicate(description, move |recorded| match recorded {
+            RecordedValue::Str(s) => regex.is_match(s),
+            RecordedValue::Debug(s) => regex.is_match(s),
+            _ => false,
+        })
+    }
+
+    fn with_predicate<F>(self, description: impl Into<String>, predicate: F) -> Self
+    where
+        F: Fn(&RecordedValue) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            value: ExpectedValue::Predicate(Box::new(predicate), description.into()),
+            ..self
+        }
+    }
+
     /// Adds an additional [`ExpectedField`] to be matched.
     ///
     /// Any fields introduced by `.and` must also match. If any fields
@@ -462,31 +851,23 @@ impl ExpectedFields {
         Self { only: true, ..self }
     }
 
-    fn compare_or_panic(
-        &mut self,
-        name: &str,
-        value: &dyn Value,
-        ctx: &str,
-        subscriber_name: &str,
-    ) {
-        let value = value.into();
+    /// Compares a recorded value against the expectation for `name`,
+    /// consuming the expectation. Returns a description of the mismatch, if
+    /// any, for the caller to accumulate rather than panicking immediately.
+    fn compare(&mut self, name: &str, value: &dyn Value) -> Option<String> {
+        let value = RecordedValue::from(value);
         match self.fields.remove(name) {
-            Some(ExpectedValue::Any) => {}
-            Some(expected) => assert!(
-                expected == value,
-                "\n[{}] expected `{}` to contain:\n\t`{}{}`\nbut got:\n\t`{}{}`",
-                subscriber_name,
-                ctx,
-                name,
-                expected,
-                name,
-                value
-            ),
-            None if self.only => panic!(
-                "[{}]expected `{}` to contain only:\n\t`{}`\nbut got:\n\t`{}{}`",
-                subscriber_name, ctx, self, name, value
-            ),
-            _ => {}
+            Some(ExpectedValue::Any) => None,
+            Some(expected) if expected == value => None,
+            Some(expected) => Some(format!(
+                "expected `{}` to contain:\n\t`{}{}`\nbut got:\n\t`{}{}`",
+                name, name, expected, name, value
+            )),
+            None if self.only => Some(format!(
+                "expected only:\n\t`{}`\nbut got unexpected field:\n\t`{}{}`",
+                self, name, value
+            )),
+            _ => None,
         }
     }
 
@@ -499,12 +880,77 @@ impl ExpectedFields {
             expect: self,
             ctx,
             subscriber_name,
+            mismatches: Vec::new(),
         }
     }
 
     pub(crate) fn is_empty(&self) -> bool {
         self.fields.is_empty()
     }
+
+    /// Builds the `logfmt`-style actual-vs-expected diagnostic included in
+    /// the panic message when an event's fields don't match. `expected_header`
+    /// and `actual_header` are the already-rendered `level=... target=...
+    /// name=...` prefixes, since metadata isn't known to this type, only to
+    /// `ExpectedEvent`.
+    ///
+    /// Unlike [`ExpectedFields::checker`], this does not consume or mutate
+    /// the expectation, so it must be called before the expectation is
+    /// handed off to a `CheckVisitor`.
+    pub(crate) fn logfmt_diff(
+        &self,
+        expected_header: &str,
+        actual_header: &str,
+        actual: &[(String, RecordedValue)],
+    ) -> String {
+        let mut expected_line = expected_header.to_string();
+        for (name, value) in &self.fields {
+            expected_line.push_str(&format!(" {}={}", name, value.logfmt()));
+        }
+
+        let mut actual_line = actual_header.to_string();
+        for (name, value) in actual {
+            actual_line.push_str(&format!(" {}={}", name, value.logfmt()));
+        }
+
+        let mut diff = format!("expected: {}\n  actual: {}", expected_line, actual_line);
+        let field_diff = self.diff_lines(actual);
+        if !field_diff.is_empty() {
+            diff.push('\n');
+            diff.push_str(&field_diff.join("\n"));
+        }
+        diff
+    }
+
+    /// Returns one line per field that's missing from, extra in (when this
+    /// expectation is `only()`), or mismatched against `actual`.
+    fn diff_lines(&self, actual: &[(String, RecordedValue)]) -> Vec<String> {
+        let mut lines = Vec::new();
+        for (name, expected) in &self.fields {
+            match actual.iter().find(|(actual_name, _)| actual_name == name) {
+                Some((_, value)) if expected == value => {}
+                Some((_, value)) => lines.push(format!(
+                    "  ~ {}: expected {}, got {}",
+                    name,
+                    expected.logfmt(),
+                    value.logfmt()
+                )),
+                None => lines.push(format!(
+                    "  - {}: missing, expected {}",
+                    name,
+                    expected.logfmt()
+                )),
+            }
+        }
+        if self.only {
+            for (name, value) in actual {
+                if !self.fields.contains_key(name) {
+                    lines.push(format!("  + {}: unexpected, got {}", name, value.logfmt()));
+                }
+            }
+        }
+        lines
+    }
 }
 
 impl fmt::Display for ExpectedValue {
@@ -513,10 +959,35 @@ impl fmt::Display for ExpectedValue {
             ExpectedValue::F64(v) => write!(f, "f64 = {:?}", v),
             ExpectedValue::I64(v) => write!(f, "i64 = {:?}", v),
             ExpectedValue::U64(v) => write!(f, "u64 = {:?}", v),
+            ExpectedValue::I128(v) => write!(f, "i128 = {:?}", v),
+            ExpectedValue::U128(v) => write!(f, "u128 = {:?}", v),
             ExpectedValue::Bool(v) => write!(f, "bool = {:?}", v),
             ExpectedValue::Str(v) => write!(f, "&str = {:?}", v),
             ExpectedValue::Debug(v) => write!(f, "&fmt::Debug = {:?}", v),
+            ExpectedValue::Error(v) => write!(f, "&dyn Error = {:?}", v),
             ExpectedValue::Any => write!(f, "_ = _"),
+            ExpectedValue::Predicate(_, description) => write!(f, "{}", description),
+        }
+    }
+}
+
+impl ExpectedValue {
+    /// Renders this expectation as the right-hand side of a `logfmt`
+    /// `key=value` pair, matching [`RecordedValue::logfmt`]'s style so the
+    /// expected and actual lines in a diff line up visually.
+    fn logfmt(&self) -> String {
+        match self {
+            ExpectedValue::F64(v) => v.to_string(),
+            ExpectedValue::I64(v) => v.to_string(),
+            ExpectedValue::U64(v) => v.to_string(),
+            ExpectedValue::I128(v) => v.to_string(),
+            ExpectedValue::U128(v) => v.to_string(),
+            ExpectedValue::Bool(v) => v.to_string(),
+            ExpectedValue::Str(v) => logfmt_quote(v),
+            ExpectedValue::Debug(v) => logfmt_quote(v),
+            ExpectedValue::Error(v) => logfmt_quote(v),
+            ExpectedValue::Any => "*".to_string(),
+            ExpectedValue::Predicate(_, description) => format!("<{}>", description),
         }
     }
 }
@@ -525,85 +996,176 @@ pub(crate) struct CheckVisitor<'a> {
     expect: &'a mut ExpectedFields,
     ctx: &'a str,
     subscriber_name: &'a str,
+    // Every mismatch found so far, accumulated rather than panicking
+    // immediately so `finish` can report them all at once.
+    mismatches: Vec<String>,
+}
+
+impl CheckVisitor<'_> {
+    fn record(&mut self, name: &str, value: &dyn Value) {
+        if let Some(mismatch) = self.expect.compare(name, value) {
+            self.mismatches.push(mismatch);
+        }
+    }
 }
 
 impl Visit for CheckVisitor<'_> {
     fn record_f64(&mut self, field: &Field, value: f64) {
-        self.expect
-            .compare_or_panic(field.name(), &value, self.ctx, self.subscriber_name)
+        self.record(field.name(), &value)
     }
 
     fn record_i64(&mut self, field: &Field, value: i64) {
-        self.expect
-            .compare_or_panic(field.name(), &value, self.ctx, self.subscriber_name)
+        self.record(field.name(), &value)
     }
 
     fn record_u64(&mut self, field: &Field, value: u64) {
-        self.expect
-            .compare_or_panic(field.name(), &value, self.ctx, self.subscriber_name)
+        self.record(field.name(), &value)
+    }
+
+    fn record_i128(&mut self, field: &Field, value: i128) {
+        self.record(field.name(), &value)
+    }
+
+    fn record_u128(&mut self, field: &Field, value: u128) {
+        self.record(field.name(), &value)
     }
 
     fn record_bool(&mut self, field: &Field, value: bool) {
-        self.expect
-            .compare_or_panic(field.name(), &value, self.ctx, self.subscriber_name)
+        self.record(field.name(), &value)
     }
 
     fn record_str(&mut self, field: &Field, value: &str) {
-        self.expect
-            .compare_or_panic(field.name(), &value, self.ctx, self.subscriber_name)
+        self.record(field.name(), &value)
     }
 
     fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
-        self.expect.compare_or_panic(
-            field.name(),
-            &field::debug(value),
-            self.ctx,
-            self.subscriber_name,
-        )
+        self.record(field.name(), &field::debug(value))
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.record(field.name(), &value)
     }
 }
 
 impl CheckVisitor<'_> {
-    pub(crate) fn finish(self) {
+    pub(crate) fn finish(mut self, diff: String) {
+        if !self.expect.fields.is_empty() {
+            self.mismatches
+                .push(format!("{}missing {}", self.expect, self.ctx));
+        }
+
         assert!(
-            self.expect.fields.is_empty(),
-            "[{}] {}missing {}",
+            self.mismatches.is_empty(),
+            "\n[{}] {}:\n{}\n\n{}",
             self.subscriber_name,
-            self.expect,
-            self.ctx
+            self.ctx,
+            self.mismatches.join("\n"),
+            diff,
         );
     }
 }
 
-impl<'a> From<&'a dyn Value> for ExpectedValue {
+/// Collects every field recorded on an event, in recording order, for
+/// building the `logfmt`-style actual-vs-expected diagnostic rendered on an
+/// `ExpectedEvent` match failure.
+///
+/// Unlike [`CheckVisitor`], this doesn't compare against an expectation; it
+/// just records what was actually there, so the diff can be built before
+/// the expectation's fields are consumed.
+#[derive(Default)]
+pub(crate) struct RecordingVisitor {
+    fields: Vec<(String, RecordedValue)>,
+}
+
+impl RecordingVisitor {
+    pub(crate) fn into_fields(self) -> Vec<(String, RecordedValue)> {
+        self.fields
+    }
+
+    fn record(&mut self, name: &str, value: &dyn Value) {
+        self.fields.push((name.to_owned(), RecordedValue::from(value)));
+    }
+}
+
+impl Visit for RecordingVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field.name(), &value)
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field.name(), &value)
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field.name(), &value)
+    }
+
+    fn record_i128(&mut self, field: &Field, value: i128) {
+        self.record(field.name(), &value)
+    }
+
+    fn record_u128(&mut self, field: &Field, value: u128) {
+        self.record(field.name(), &value)
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record(field.name(), &value)
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field.name(), &value)
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field.name(), &field::debug(value))
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.record(field.name(), &value)
+    }
+}
+
+impl<'a> From<&'a dyn Value> for RecordedValue {
     fn from(value: &'a dyn Value) -> Self {
         struct MockValueBuilder {
-            value: Option<ExpectedValue>,
+            value: Option<RecordedValue>,
         }
 
         impl Visit for MockValueBuilder {
             fn record_f64(&mut self, _: &Field, value: f64) {
-                self.value = Some(ExpectedValue::F64(value));
+                self.value = Some(RecordedValue::F64(value));
             }
 
             fn record_i64(&mut self, _: &Field, value: i64) {
-                self.value = Some(ExpectedValue::I64(value));
+                self.value = Some(RecordedValue::I64(value));
             }
 
             fn record_u64(&mut self, _: &Field, value: u64) {
-                self.value = Some(ExpectedValue::U64(value));
+                self.value = Some(RecordedValue::U64(value));
+            }
+
+            fn record_i128(&mut self, _: &Field, value: i128) {
+                self.value = Some(RecordedValue::I128(value));
+            }
+
+            fn record_u128(&mut self, _: &Field, value: u128) {
+                self.value = Some(RecordedValue::U128(value));
             }
 
             fn record_bool(&mut self, _: &Field, value: bool) {
-                self.value = Some(ExpectedValue::Bool(value));
+                self.value = Some(RecordedValue::Bool(value));
             }
 
             fn record_str(&mut self, _: &Field, value: &str) {
-                self.value = Some(ExpectedValue::Str(value.to_owned()));
+                self.value = Some(RecordedValue::Str(value.to_owned()));
             }
 
             fn record_debug(&mut self, _: &Field, value: &dyn fmt::Debug) {
-                self.value = Some(ExpectedValue::Debug(format!("{:?}", value)));
+                self.value = Some(RecordedValue::Debug(format!("{:?}", value)));
+            }
+
+            fn record_error(&mut self, _: &Field, value: &(dyn std::error::Error + 'static)) {
+                self.value = Some(RecordedValue::Error(render_error(value)));
             }
         }
 
@@ -620,6 +1182,12 @@ impl<'a> From<&'a dyn Value> for ExpectedValue {
     }
 }
 
+impl<'a> From<&'a dyn Value> for ExpectedValue {
+    fn from(value: &'a dyn Value) -> Self {
+        RecordedValue::from(value).into()
+    }
+}
+
 impl fmt::Display for ExpectedFields {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "fields ")?;
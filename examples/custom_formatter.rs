@@ -14,7 +14,10 @@ use tracing_subscriber::{
         format::{FormatEvent, FormatFields},
         FmtContext, FormattedFields,
     },
-    layer::{transform::FieldTransformLayer, SubscriberExt},
+    layer::{
+        transform::{FieldTransformLayer, TransformFormatFields},
+        SubscriberExt,
+    },
     registry::{LookupSpan, Registry},
     util::SubscriberInitExt,
 };
@@ -76,8 +79,11 @@ where
         // Write the event message
         write!(writer, " | {}", event.metadata().name())?;
 
-        // Note: Event fields would normally be transformed and displayed here
-        // but we'll keep this simple for the example
+        // Write the event's own fields, running them through `ctx.field_format()` so that
+        // anything wrapped in `TransformFormatFields` (hidden/truncated/transformed fields)
+        // is reflected here too, not just on span attributes.
+        write!(writer, " ")?;
+        ctx.format_fields(writer.by_ref(), event)?;
 
         writeln!(writer)?;
         Ok(())
@@ -152,9 +158,12 @@ mod database {
 }
 
 fn main() {
-    // Configure field transformations to clean up verbose third-party logs
-    let transform_layer = FieldTransformLayer::new()
-        .with_target_transform("http", |builder| {
+    // Configure field transformations to clean up verbose third-party logs. Use a reload
+    // handle so the exact same live rule set also drives event-field transformation below.
+    let (transform_layer, handle) = FieldTransformLayer::new_with_handle();
+    handle.modify(|config| {
+        config
+        .add_target_transform("http", |builder| {
             builder
                 .hide_field("connection_pool_size") // Implementation detail
                 .hide_field("keep_alive") // Usually not relevant
@@ -192,7 +201,7 @@ fn main() {
                     }
                 })
         })
-        .with_target_transform("db", |builder| {
+        .add_target_transform("db", |builder| {
             builder
                 .rename_field("connection_id", "conn") // Shorter
                 .rename_field("database_name", "db") // Shorter
@@ -207,13 +216,22 @@ fn main() {
                         101..=1000 => format!("🟡 {}ms", ms),
                         _ => format!("🔴 {}ms", ms),
                     }
-                })
-        });
+                });
+    });
 
-    // Initialize with custom formatter and transformations
+    // Initialize with custom formatter and transformations. `TransformFormatFields` shares
+    // the same reload handle as `transform_layer`, so event fields (not just span fields)
+    // get hidden/truncated/transformed before `CustomFormatter` ever sees them.
     Registry::default()
         .with(transform_layer)
-        .with(tracing_subscriber::fmt::layer().event_format(CustomFormatter))
+        .with(
+            tracing_subscriber::fmt::layer()
+                .event_format(CustomFormatter)
+                .fmt_fields(TransformFormatFields::with_handle(
+                    tracing_subscriber::fmt::format::DefaultFields::new(),
+                    &handle,
+                )),
+        )
         .init();
 
     println!("=== Custom Formatter + Field Transformations Example ===\n");
@@ -0,0 +1,325 @@
+//! Combinators for combining two [`Filter`]s into one.
+use crate::layer::{Context, Filter};
+use std::{cmp, fmt, marker::PhantomData};
+use tracing_core::{span, subscriber::Interest, Event, LevelFilter, Metadata, Subscriber};
+
+/// Combines two [`Filter`]s so that a span or event is enabled only if both filters enable it.
+///
+/// Returned by [`FilterExt::and`].
+pub struct And<A, B, S> {
+    a: A,
+    b: B,
+    _s: PhantomData<fn(S)>,
+}
+
+/// Combines two [`Filter`]s so that a span or event is enabled if either filter enables it.
+///
+/// Returned by [`FilterExt::or`].
+pub struct Or<A, B, S> {
+    a: A,
+    b: B,
+    _s: PhantomData<fn(S)>,
+}
+
+/// Inverts the result of a [`Filter`].
+///
+/// Returned by [`FilterExt::not`].
+pub struct Not<F, S> {
+    f: F,
+    _s: PhantomData<fn(S)>,
+}
+
+/// Extension trait adding combinators to every [`Filter`].
+///
+/// This is implemented for all `F: Filter<S>`, so any filter — [`LevelFilter`], a closure
+/// wrapped in [`filter_fn`](super::filter_fn), a [`FieldFilter`](super::FieldFilter), or one of
+/// these combinators itself — can be combined with `.and()`/`.or()`/`.not()`.
+pub trait FilterExt<S>: Filter<S> {
+    /// Combines `self` with `other`, returning a new [`Filter`] that enables a span or event
+    /// only if *both* filters enable it.
+    fn and<B>(self, other: B) -> And<Self, B, S>
+    where
+        Self: Sized,
+        B: Filter<S>,
+    {
+        And {
+            a: self,
+            b: other,
+            _s: PhantomData,
+        }
+    }
+
+    /// Combines `self` with `other`, returning a new [`Filter`] that enables a span or event
+    /// if *either* filter enables it.
+    fn or<B>(self, other: B) -> Or<Self, B, S>
+    where
+        Self: Sized,
+        B: Filter<S>,
+    {
+        Or {
+            a: self,
+            b: other,
+            _s: PhantomData,
+        }
+    }
+
+    /// Inverts `self`, returning a new [`Filter`] that enables whatever `self` disables.
+    ///
+    /// Because negating a filter's interest can't be reasoned about at the callsite level (a
+    /// callsite `self` would always/never enable might, once inverted, go the other way at
+    /// runtime), the returned filter always reports [`Interest::sometimes()`][sometimes] and
+    /// `None` for [`max_level_hint`](Filter::max_level_hint), so it's always re-checked per
+    /// event/span rather than cached.
+    ///
+    /// [sometimes]: tracing_core::subscriber::Interest::sometimes
+    fn not(self) -> Not<Self, S>
+    where
+        Self: Sized,
+    {
+        Not {
+            f: self,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<F, S> FilterExt<S> for F where F: Filter<S> {}
+
+impl<A, B, S> Filter<S> for And<A, B, S>
+where
+    A: Filter<S>,
+    B: Filter<S>,
+{
+    fn enabled(&self, meta: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        self.a.enabled(meta, cx) && self.b.enabled(meta, cx)
+    }
+
+    fn event_enabled(&self, event: &Event<'_>, cx: &Context<'_, S>) -> bool {
+        self.a.event_enabled(event, cx) && self.b.event_enabled(event, cx)
+    }
+
+    fn callsite_enabled(&self, meta: &'static Metadata<'static>) -> Interest {
+        let a = self.a.callsite_enabled(meta);
+        let b = self.b.callsite_enabled(meta);
+        // The combined interest is the *less* permissive of the two: never < sometimes < always.
+        if a.is_never() || b.is_never() {
+            Interest::never()
+        } else if a.is_always() && b.is_always() {
+            Interest::always()
+        } else {
+            Interest::sometimes()
+        }
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        // A missing hint means "no hint", i.e. the loosest possible bound, so it yields to
+        // whichever side actually has one; tightest wins when both do.
+        match (self.a.max_level_hint(), self.b.max_level_hint()) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (hint, None) | (None, hint) => hint,
+        }
+    }
+
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_new_span(attrs, id, cx.clone());
+        self.b.on_new_span(attrs, id, cx);
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_record(id, values, cx.clone());
+        self.b.on_record(id, values, cx);
+    }
+
+    fn on_enter(&self, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_enter(id, cx.clone());
+        self.b.on_enter(id, cx);
+    }
+
+    fn on_exit(&self, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_exit(id, cx.clone());
+        self.b.on_exit(id, cx);
+    }
+
+    fn on_close(&self, id: span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_close(id.clone(), cx.clone());
+        self.b.on_close(id, cx);
+    }
+}
+
+impl<A, B, S> Filter<S> for Or<A, B, S>
+where
+    A: Filter<S>,
+    B: Filter<S>,
+{
+    fn enabled(&self, meta: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        self.a.enabled(meta, cx) || self.b.enabled(meta, cx)
+    }
+
+    fn event_enabled(&self, event: &Event<'_>, cx: &Context<'_, S>) -> bool {
+        self.a.event_enabled(event, cx) || self.b.event_enabled(event, cx)
+    }
+
+    fn callsite_enabled(&self, meta: &'static Metadata<'static>) -> Interest {
+        let a = self.a.callsite_enabled(meta);
+        let b = self.b.callsite_enabled(meta);
+        // The combined interest is the *more* permissive of the two: always > sometimes > never.
+        if a.is_always() || b.is_always() {
+            Interest::always()
+        } else if a.is_never() && b.is_never() {
+            Interest::never()
+        } else {
+            Interest::sometimes()
+        }
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        // An `Or` is only as tight as its loosest side, so an unhinted side makes the whole
+        // combination unhinted too.
+        match (self.a.max_level_hint(), self.b.max_level_hint()) {
+            (Some(a), Some(b)) => Some(cmp::max(a, b)),
+            _ => None,
+        }
+    }
+
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_new_span(attrs, id, cx.clone());
+        self.b.on_new_span(attrs, id, cx);
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_record(id, values, cx.clone());
+        self.b.on_record(id, values, cx);
+    }
+
+    fn on_enter(&self, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_enter(id, cx.clone());
+        self.b.on_enter(id, cx);
+    }
+
+    fn on_exit(&self, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_exit(id, cx.clone());
+        self.b.on_exit(id, cx);
+    }
+
+    fn on_close(&self, id: span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.a.on_close(id.clone(), cx.clone());
+        self.b.on_close(id, cx);
+    }
+}
+
+impl<F, S> Filter<S> for Not<F, S>
+where
+    F: Filter<S>,
+{
+    fn enabled(&self, meta: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        !self.f.enabled(meta, cx)
+    }
+
+    fn event_enabled(&self, event: &Event<'_>, cx: &Context<'_, S>) -> bool {
+        !self.f.event_enabled(event, cx)
+    }
+
+    fn callsite_enabled(&self, _meta: &'static Metadata<'static>) -> Interest {
+        // Whatever interest `self.f` reports, negating it could flip `always`/`never` into
+        // the opposite at any given callsite, so the only sound answer is "ask every time".
+        Interest::sometimes()
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        None
+    }
+
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.f.on_new_span(attrs, id, cx);
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.f.on_record(id, values, cx);
+    }
+
+    fn on_enter(&self, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.f.on_enter(id, cx);
+    }
+
+    fn on_exit(&self, id: &span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.f.on_exit(id, cx);
+    }
+
+    fn on_close(&self, id: span::Id, cx: Context<'_, S>)
+    where
+        S: Subscriber,
+    {
+        self.f.on_close(id, cx);
+    }
+}
+
+impl<A, B, S> fmt::Debug for And<A, B, S>
+where
+    A: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("And").field("a", &self.a).field("b", &self.b).finish()
+    }
+}
+
+impl<A, B, S> fmt::Debug for Or<A, B, S>
+where
+    A: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Or").field("a", &self.a).field("b", &self.b).finish()
+    }
+}
+
+impl<F, S> fmt::Debug for Not<F, S>
+where
+    F: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Not").field("f", &self.f).finish()
+    }
+}
@@ -0,0 +1,224 @@
+//! A reusable, declarative [`Filter`] that gates events on recorded field values.
+use crate::{
+    field::Visit,
+    layer::{Context, Filter},
+};
+use std::fmt;
+use tracing_core::{field::Field, Event, Metadata, Subscriber};
+
+/// A value a recorded field's contents are checked against.
+#[derive(Debug, Clone)]
+enum Predicate {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Predicate {
+    fn matches_rendered(&self, rendered: &str) -> bool {
+        match self {
+            Predicate::Bool(b) => rendered.parse::<bool>().map(|v| v == *b).unwrap_or(false),
+            Predicate::I64(n) => rendered.parse::<i64>().map(|v| v == *n).unwrap_or(false),
+            Predicate::U64(n) => rendered.parse::<u64>().map(|v| v == *n).unwrap_or(false),
+            Predicate::F64(n) => rendered.parse::<f64>().map(|v| v == *n).unwrap_or(false),
+            #[cfg(feature = "regex")]
+            Predicate::Regex(re) => re.is_match(rendered),
+        }
+    }
+
+    fn matches_bool(&self, value: bool) -> bool {
+        match self {
+            Predicate::Bool(b) => *b == value,
+            _ => self.matches_rendered(&value.to_string()),
+        }
+    }
+
+    fn matches_i64(&self, value: i64) -> bool {
+        match self {
+            Predicate::I64(n) => *n == value,
+            _ => self.matches_rendered(&value.to_string()),
+        }
+    }
+
+    fn matches_u64(&self, value: u64) -> bool {
+        match self {
+            Predicate::U64(n) => *n == value,
+            _ => self.matches_rendered(&value.to_string()),
+        }
+    }
+
+    fn matches_f64(&self, value: f64) -> bool {
+        match self {
+            Predicate::F64(n) => *n == value,
+            _ => self.matches_rendered(&value.to_string()),
+        }
+    }
+}
+
+impl From<bool> for Predicate {
+    fn from(value: bool) -> Self {
+        Predicate::Bool(value)
+    }
+}
+
+impl From<i64> for Predicate {
+    fn from(value: i64) -> Self {
+        Predicate::I64(value)
+    }
+}
+
+impl From<u64> for Predicate {
+    fn from(value: u64) -> Self {
+        Predicate::U64(value)
+    }
+}
+
+impl From<f64> for Predicate {
+    fn from(value: f64) -> Self {
+        Predicate::F64(value)
+    }
+}
+
+/// How multiple conditions on a [`FieldFilter`] combine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    All,
+    Any,
+}
+
+/// A declarative, reusable [`Filter`] that gates events on the values of one or more
+/// recorded fields.
+///
+/// This encodes the pattern used by the hand-written `FilterEvent`/`ShouldEnable` visitor
+/// in the per-layer filter tests, so content-based sampling doesn't require writing a
+/// [`Visit`] implementation by hand:
+///
+/// ```rust
+/// use better_tracing::{filter::FieldFilter, prelude::*};
+///
+/// let filter = FieldFilter::new().when("enable", true);
+///
+/// let _subscriber = better_tracing::registry()
+///     .with(better_tracing::fmt::layer().with_filter(filter));
+/// ```
+///
+/// By default all configured conditions must match ([`require_all`](Self::require_all));
+/// call [`require_any`](Self::require_any) to accept events matching at least one.
+#[derive(Debug, Clone, Default)]
+pub struct FieldFilter {
+    conditions: Vec<(&'static str, Predicate)>,
+    combinator: Combinator,
+}
+
+impl Default for Combinator {
+    fn default() -> Self {
+        Combinator::All
+    }
+}
+
+impl FieldFilter {
+    /// Create a filter with no conditions. With no conditions configured, every event is
+    /// enabled.
+    pub fn new() -> Self {
+        Self {
+            conditions: Vec::new(),
+            combinator: Combinator::All,
+        }
+    }
+
+    /// Require `field` to be recorded with exactly `value`.
+    pub fn when(mut self, field: &'static str, value: impl Into<Predicate>) -> Self {
+        self.conditions.push((field, value.into()));
+        self
+    }
+
+    /// Require `field`'s string or `Debug` rendering to match `regex`.
+    #[cfg(feature = "regex")]
+    pub fn when_matches(mut self, field: &'static str, regex: regex::Regex) -> Self {
+        self.conditions.push((field, Predicate::Regex(regex)));
+        self
+    }
+
+    /// All configured conditions must match for an event to be enabled (the default).
+    pub fn require_all(mut self) -> Self {
+        self.combinator = Combinator::All;
+        self
+    }
+
+    /// At least one configured condition must match for an event to be enabled.
+    pub fn require_any(mut self) -> Self {
+        self.combinator = Combinator::Any;
+        self
+    }
+}
+
+struct FieldFilterVisitor<'a> {
+    conditions: &'a [(&'static str, Predicate)],
+    matched: Vec<bool>,
+}
+
+impl FieldFilterVisitor<'_> {
+    fn record(&mut self, name: &str, check: impl Fn(&Predicate) -> bool) {
+        for (i, (field, predicate)) in self.conditions.iter().enumerate() {
+            if *field == name && check(predicate) {
+                self.matched[i] = true;
+            }
+        }
+    }
+}
+
+impl Visit for FieldFilterVisitor<'_> {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record(field.name(), |p| p.matches_bool(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field.name(), |p| p.matches_i64(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field.name(), |p| p.matches_u64(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field.name(), |p| p.matches_f64(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field.name(), |p| p.matches_rendered(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let rendered = format!("{:?}", value);
+        self.record(field.name(), |p| p.matches_rendered(&rendered));
+    }
+}
+
+impl<S> Filter<S> for FieldFilter
+where
+    S: Subscriber,
+{
+    fn enabled(&self, _meta: &Metadata<'_>, _cx: &Context<'_, S>) -> bool {
+        true
+    }
+
+    fn event_enabled(&self, event: &Event<'_>, _cx: &Context<'_, S>) -> bool {
+        if self.conditions.is_empty() {
+            return true;
+        }
+
+        let mut visitor = FieldFilterVisitor {
+            conditions: &self.conditions,
+            matched: vec![false; self.conditions.len()],
+        };
+        event.record(&mut visitor);
+
+        match self.combinator {
+            Combinator::All => visitor.matched.iter().all(|&m| m),
+            Combinator::Any => visitor.matched.iter().any(|&m| m),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! `Filter`s for limiting what spans and events are collected by a [`Layer`].
+//!
+//! [`Layer`]: crate::layer::Layer
+
+mod field_filter;
+
+pub mod combinator;
+
+pub use combinator::{And, FilterExt, Not, Or};
+pub use field_filter::FieldFilter;
@@ -6,7 +6,12 @@ use crate::{
 };
 use format::{FmtSpan, TimingDisplay};
 use std::{
-    any::TypeId, cell::RefCell, env, fmt, io, marker::PhantomData, ops::Deref, time::Instant,
+    any::TypeId,
+    cell::RefCell,
+    env, fmt, io,
+    marker::PhantomData,
+    ops::Deref,
+    time::{Duration, Instant},
 };
 use tracing_core::{
     field,
@@ -73,6 +78,8 @@ pub struct Layer<
     fmt_span: format::FmtSpanConfig,
     is_ansi: bool,
     log_internal_errors: bool,
+    numeric_timings: bool,
+    min_busy: Option<Duration>,
     _inner: PhantomData<fn(S)>,
 }
 
@@ -123,6 +130,8 @@ where
             make_writer: self.make_writer,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
@@ -153,6 +162,8 @@ where
             make_writer: self.make_writer,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
@@ -186,6 +197,8 @@ impl<S, N, E, W> Layer<S, N, E, W> {
             fmt_span: self.fmt_span,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             make_writer,
             _inner: self._inner,
         }
@@ -262,6 +275,17 @@ impl<S, N, E, W> Layer<S, N, E, W> {
         }
     }
 
+    /// Modifies the minimum busy-time threshold below which the synthesized
+    /// `close` span event is suppressed (see [`Layer::with_span_min_busy`]).
+    ///
+    /// This is primarily expected to be used with the
+    /// [`reload::Handle::modify`](crate::reload::Handle::modify) method,
+    /// the same way [`set_span_events`](Self::set_span_events) is, so the
+    /// slow-span threshold can be adjusted on a running subscriber.
+    pub fn set_span_min_busy(&mut self, threshold: impl Into<Option<Duration>>) {
+        self.min_busy = threshold.into();
+    }
+
     /// Configures the layer to support [`libtest`'s output capturing][capturing] when used in
     /// unit tests.
     ///
@@ -291,6 +315,8 @@ impl<S, N, E, W> Layer<S, N, E, W> {
             fmt_span: self.fmt_span,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             make_writer: TestWriter::default(),
             _inner: self._inner,
         }
@@ -358,6 +384,45 @@ impl<S, N, E, W> Layer<S, N, E, W> {
         }
     }
 
+    /// Sets whether the `time.busy` and `time.idle` fields on span close events are
+    /// recorded as raw nanosecond [`u64`] values rather than human-readable strings.
+    ///
+    /// By default, these fields are recorded via their `Display` impl (e.g.
+    /// `"1.23ms"`), which reads well in the `Full`, `Compact`, and `Pretty`
+    /// formatters. Structured formatters such as [`Json`][super::format::Json]
+    /// instead benefit from plain numbers that downstream log pipelines can
+    /// aggregate without parsing a unit suffix. Enable this when using a
+    /// structured formatter; leave it disabled for the text formatters.
+    /// [`Layer::json`] and [`Layer::bunyan`] turn it on themselves, overriding
+    /// any earlier call to this method.
+    pub fn with_numeric_timings(self, numeric_timings: bool) -> Self {
+        Self {
+            numeric_timings,
+            ..self
+        }
+    }
+
+    /// Suppresses the synthetic `close` span event unless the span's busy
+    /// time was at least `threshold`.
+    ///
+    /// This turns `FmtSpan::CLOSE`/`FmtSpan::FULL` tracking into a cheap
+    /// slow-operation detector: rather than logging every span close, only
+    /// spans that were actually entered for at least `threshold` are
+    /// logged, with the usual `time.busy`/`time.idle` fields attached. This
+    /// has no effect unless `with_span_events` is also configured to trace
+    /// span closes, and it does not affect the `new`/`enter`/`exit`
+    /// synthetic events. When span timing is disabled (for example with
+    /// `without_time`), busy time is not tracked and every close is logged.
+    ///
+    /// Pass `None` to disable the threshold and log every close (the
+    /// default).
+    pub fn with_span_min_busy(self, threshold: impl Into<Option<Duration>>) -> Self {
+        Self {
+            min_busy: threshold.into(),
+            ..self
+        }
+    }
+
     /// Updates the [`MakeWriter`] by applying a function to the existing [`MakeWriter`].
     ///
     /// This sets the [`MakeWriter`] that the layer being built will use to write events.
@@ -387,6 +452,8 @@ impl<S, N, E, W> Layer<S, N, E, W> {
             fmt_span: self.fmt_span,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             make_writer: f(self.make_writer),
             _inner: self._inner,
         }
@@ -419,6 +486,8 @@ where
             make_writer: self.make_writer,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
@@ -432,6 +501,8 @@ where
             make_writer: self.make_writer,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
@@ -561,6 +632,8 @@ where
             make_writer: self.make_writer,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
@@ -576,6 +649,8 @@ where
             make_writer: self.make_writer,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
@@ -607,11 +682,87 @@ where
             // always disable ANSI escapes in JSON mode!
             is_ansi: false,
             log_internal_errors: self.log_internal_errors,
+            // JSON output benefits from numeric time.busy/time.idle fields by default.
+            numeric_timings: true,
+            min_busy: self.min_busy,
+            _inner: self._inner,
+        }
+    }
+
+    /// Sets the layer being built to use a [node-bunyan]-compatible JSON formatter.
+    ///
+    /// `name` is reported as the bunyan record's `"name"` field, identifying which
+    /// service emitted the log line.
+    ///
+    /// [`Layer::with_pid`] and [`Layer::with_hostname`] can be used to omit the `"pid"`
+    /// and `"hostname"` fields respectively.
+    ///
+    /// [node-bunyan]: https://github.com/trentm/node-bunyan
+    /// [`Layer::with_pid`]: Layer::with_pid()
+    /// [`Layer::with_hostname`]: Layer::with_hostname()
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn bunyan(
+        self,
+        name: impl Into<String>,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Bunyan, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.bunyan(name),
+            fmt_fields: format::JsonFields::new(),
+            fmt_span: self.fmt_span,
+            make_writer: self.make_writer,
+            // always disable ANSI escapes in JSON mode!
+            is_ansi: false,
+            log_internal_errors: self.log_internal_errors,
+            numeric_timings: true,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
 }
 
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+impl<S, T, W> Layer<S, format::JsonFields, format::Format<format::Bunyan, T>, W> {
+    /// Sets whether or not the bunyan-formatted record will include a `"pid"` field.
+    ///
+    /// See [`format::Bunyan`][super::format::Bunyan]
+    pub fn with_pid(
+        self,
+        include_pid: bool,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Bunyan, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_pid(include_pid),
+            ..self
+        }
+    }
+
+    /// Sets whether or not the bunyan-formatted record will include a `"hostname"` field.
+    ///
+    /// See [`format::Bunyan`][super::format::Bunyan]
+    pub fn with_hostname(
+        self,
+        include_hostname: bool,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Bunyan, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_hostname(include_hostname),
+            ..self
+        }
+    }
+
+    /// Registers `mapper` to customize how each field recorded on a span or event is
+    /// encoded; see [`format::JsonFieldMapper`].
+    pub fn with_field_mapper(
+        self,
+        mapper: impl format::JsonFieldMapper + 'static,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Bunyan, T>, W> {
+        Layer {
+            fmt_fields: self.fmt_fields.with_field_mapper(mapper),
+            ..self
+        }
+    }
+}
+
 #[cfg(feature = "json")]
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 impl<S, T, W> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
@@ -624,7 +775,6 @@ impl<S, T, W> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
     ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
         Layer {
             fmt_event: self.fmt_event.flatten_event(flatten_event),
-            fmt_fields: format::JsonFields::new(),
             ..self
         }
     }
@@ -639,7 +789,6 @@ impl<S, T, W> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
     ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
         Layer {
             fmt_event: self.fmt_event.with_current_span(display_current_span),
-            fmt_fields: format::JsonFields::new(),
             ..self
         }
     }
@@ -654,7 +803,185 @@ impl<S, T, W> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
     ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
         Layer {
             fmt_event: self.fmt_event.with_span_list(display_span_list),
-            fmt_fields: format::JsonFields::new(),
+            ..self
+        }
+    }
+
+    /// Renames the `"timestamp"` key the JSON layer being built uses.
+    ///
+    /// See [`format::Json`][super::format::Json]
+    pub fn with_timestamp_key(
+        self,
+        key: impl Into<String>,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_timestamp_key(key),
+            ..self
+        }
+    }
+
+    /// Renames the `"level"` key the JSON layer being built uses.
+    ///
+    /// See [`format::Json`][super::format::Json]
+    pub fn with_level_key(
+        self,
+        key: impl Into<String>,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_level_key(key),
+            ..self
+        }
+    }
+
+    /// Renames the event's `"message"` field when [`flatten_event`](Self::flatten_event) is
+    /// enabled.
+    ///
+    /// See [`format::Json`][super::format::Json]
+    pub fn with_message_key(
+        self,
+        key: impl Into<String>,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_message_key(key),
+            ..self
+        }
+    }
+
+    /// Chooses how the event's level is encoded; see [`format::LevelFormat`].
+    ///
+    /// See [`format::Json`][super::format::Json]
+    pub fn with_level_format(
+        self,
+        level_format: format::LevelFormat,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_level_format(level_format),
+            ..self
+        }
+    }
+
+    /// Chooses how a [`flatten_event`](Self::flatten_event)ed field that collides with one
+    /// of the object's built-in keys is resolved; see [`format::FieldCollision`].
+    ///
+    /// See [`format::Json`][super::format::Json]
+    pub fn with_field_collision(
+        self,
+        collision: format::FieldCollision,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_field_collision(collision),
+            ..self
+        }
+    }
+
+    /// Registers `mapper` to customize how each field recorded on a span or event is
+    /// encoded; see [`format::JsonFieldMapper`].
+    pub fn with_field_mapper(
+        self,
+        mapper: impl format::JsonFieldMapper + 'static,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_fields: self.fmt_fields.with_field_mapper(mapper),
+            ..self
+        }
+    }
+
+    /// Chooses how byte-slice fields are encoded; see
+    /// [`format::JsonFields::with_bytes_encoding`][super::format::JsonFields::with_bytes_encoding()].
+    pub fn with_bytes_encoding(
+        self,
+        encoding: format::BytesEncoding,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_fields: self.fmt_fields.with_bytes_encoding(encoding),
+            ..self
+        }
+    }
+
+    /// Renames the `"target"` key the JSON layer being built uses.
+    ///
+    /// See [`format::Json`][super::format::Json]
+    pub fn with_target_key(
+        self,
+        key: impl Into<String>,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_target_key(key),
+            ..self
+        }
+    }
+
+    /// Renames the `"fields"` key that event fields are nested under when
+    /// [`flatten_event`](Self::flatten_event) is disabled.
+    ///
+    /// See [`format::Json`][super::format::Json]
+    pub fn with_fields_key(
+        self,
+        key: impl Into<String>,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_fields_key(key),
+            ..self
+        }
+    }
+
+    /// Configures the JSON layer being built to match the [Elastic Common Schema]
+    /// convention; see [`format::Json::ecs`][super::format::Json::ecs()].
+    ///
+    /// [Elastic Common Schema]: https://www.elastic.co/guide/en/ecs/current/index.html
+    pub fn ecs(self) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.ecs(),
+            ..self
+        }
+    }
+
+    /// Configures the JSON layer being built to match the [Google Cloud Logging] structured
+    /// payload convention; see [`format::Json::gcp`][super::format::Json::gcp()].
+    ///
+    /// [Google Cloud Logging]: https://cloud.google.com/logging/docs/structured-logging
+    pub fn gcp(self) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.gcp(),
+            ..self
+        }
+    }
+
+    /// Sets whether or not each record is pretty-printed across multiple lines, instead of
+    /// compact single-line JSON; see [`format::Json::pretty`][super::format::Json::pretty()].
+    ///
+    /// Named `json_pretty` rather than `pretty` to avoid colliding with
+    /// [`Layer::pretty`](Layer::pretty()), which switches to an entirely different formatter.
+    pub fn json_pretty(
+        self,
+        pretty: bool,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.pretty(pretty),
+            ..self
+        }
+    }
+
+    /// Injects `fields` into the root object of every formatted record; see
+    /// [`format::Json::with_static_fields`][super::format::Json::with_static_fields()].
+    pub fn with_static_fields(
+        self,
+        fields: impl IntoIterator<Item = (String, serde_json::Value)>,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.with_static_fields(fields),
+            ..self
+        }
+    }
+
+    /// Flattens every ancestor span's fields into the root object with the given key prefix;
+    /// see [`format::Json::flatten_span_fields`][super::format::Json::flatten_span_fields()].
+    pub fn flatten_span_fields(
+        self,
+        prefix: impl Into<String>,
+    ) -> Layer<S, format::JsonFields, format::Format<format::Json, T>, W> {
+        Layer {
+            fmt_event: self.fmt_event.flatten_span_fields(prefix),
             ..self
         }
     }
@@ -674,6 +1001,8 @@ impl<S, N, E, W> Layer<S, N, E, W> {
             make_writer: self.make_writer,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
@@ -705,6 +1034,8 @@ impl<S, N, E, W> Layer<S, N, E, W> {
             make_writer: self.make_writer,
             is_ansi: self.is_ansi,
             log_internal_errors: self.log_internal_errors,
+            numeric_timings: self.numeric_timings,
+            min_busy: self.min_busy,
             _inner: self._inner,
         }
     }
@@ -723,6 +1054,8 @@ impl<S> Default for Layer<S> {
             make_writer: io::stdout,
             is_ansi: ansi,
             log_internal_errors: false,
+            numeric_timings: false,
+            min_busy: None,
             _inner: PhantomData,
         }
     }
@@ -744,6 +1077,67 @@ where
             exiting_span: None,
         }
     }
+
+    /// Formats `event` with `fmt_ctx` and writes the result to this layer's
+    /// [`MakeWriter`], flushing afterwards.
+    ///
+    /// This is the shared tail end of `on_event` and of the synthetic
+    /// `new`/`enter`/`exit`/`close` span lifecycle events: format into a
+    /// thread-local buffer, write it out, and flush so line-buffered or
+    /// network-backed writers don't strand partial output.
+    fn emit_event(&self, fmt_ctx: &FmtContext<'_, S, N>, event: &Event<'_>) {
+        thread_local! {
+            static BUF: RefCell<String> = const { RefCell::new(String::new()) };
+        }
+
+        BUF.with(|buf| {
+            let borrow = buf.try_borrow_mut();
+            let mut a;
+            let mut b;
+            let mut buf = match borrow {
+                Ok(buf) => {
+                    a = buf;
+                    &mut *a
+                }
+                _ => {
+                    b = String::new();
+                    &mut b
+                }
+            };
+
+            if self
+                .fmt_event
+                .format_event(
+                    fmt_ctx,
+                    format::Writer::new(&mut buf).with_ansi(self.is_ansi),
+                    event,
+                )
+                .is_ok()
+            {
+                let mut writer = self.make_writer.make_writer_for(event.metadata());
+                let write_res = io::Write::write_all(&mut writer, buf.as_bytes());
+                let flush_res = write_res.and_then(|_| io::Write::flush(&mut writer));
+                if self.log_internal_errors {
+                    if let Err(e) = flush_res {
+                        eprintln!("[better-tracing] Unable to write an event to the Writer for this Subscriber! Error: {}\n", e);
+                    }
+                }
+            } else if self.log_internal_errors {
+                let err_msg = format!(
+                    "Unable to format the following event. Name: {}; Fields: {:?}\n",
+                    event.metadata().name(),
+                    event.fields()
+                );
+                let mut writer = self.make_writer.make_writer_for(event.metadata());
+                let res = io::Write::write_all(&mut writer, err_msg.as_bytes());
+                if let Err(e) = res {
+                    eprintln!("[better-tracing] Unable to write an \"event formatting error\" to the Writer for this Subscriber! Error: {}\n", e);
+                }
+            }
+
+            buf.clear();
+        });
+    }
 }
 
 /// A formatted representation of a span's fields stored in its [extensions].
@@ -858,6 +1252,19 @@ where
             extensions.insert(Timings::new());
         }
 
+        if extensions.get_mut::<SpanOpenedAt>().is_none() {
+            extensions.insert(SpanOpenedAt(Instant::now()));
+        }
+
+        #[cfg(feature = "json")]
+        if let Some(json_fields) =
+            (&self.fmt_fields as &dyn std::any::Any).downcast_ref::<format::JsonFields>()
+        {
+            if extensions.get_mut::<format::JsonFieldsMap>().is_none() {
+                extensions.insert(json_fields.record_new(attrs));
+            }
+        }
+
         if self.fmt_span.trace_new() {
             with_event_from_span!(id, span, "message" = "new", |event| {
                 drop(extensions);
@@ -870,6 +1277,17 @@ where
     fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
         let span = ctx.span(id).expect("Span not found, this is a bug");
         let mut extensions = span.extensions_mut();
+
+        #[cfg(feature = "json")]
+        if let Some(json_fields) =
+            (&self.fmt_fields as &dyn std::any::Any).downcast_ref::<format::JsonFields>()
+        {
+            match extensions.get_mut::<format::JsonFieldsMap>() {
+                Some(map) => json_fields.record_into(map, values),
+                None => extensions.insert(json_fields.record_new(values)),
+            }
+        }
+
         if let Some(fields) = extensions.get_mut::<FormattedFields<N>>() {
             let _ = self.fmt_fields.add_fields(fields, values);
             return;
@@ -895,6 +1313,7 @@ where
                     let now = Instant::now();
                     timings.idle += (now - timings.last).as_nanos() as u64;
                     timings.last = now;
+                    timings.enter_total += 1;
                 }
                 timings.entered_count += 1;
             }
@@ -917,7 +1336,9 @@ where
                 timings.entered_count -= 1;
                 if timings.entered_count == 0 {
                     let now = Instant::now();
-                    timings.busy += (now - timings.last).as_nanos() as u64;
+                    let interval = (now - timings.last).as_nanos() as u64;
+                    timings.busy += interval;
+                    timings.max_busy = timings.max_busy.max(interval);
                     timings.last = now;
                 }
             }
@@ -936,53 +1357,7 @@ where
                         exiting_span: Some(span_id), // Use the cloned span ID
                     };
 
-                    thread_local! {
-                        static BUF: RefCell<String> = const { RefCell::new(String::new()) };
-                    }
-
-                    BUF.with(|buf| {
-                        let borrow = buf.try_borrow_mut();
-                        let mut a;
-                        let mut b;
-                        let mut buf = match borrow {
-                            Ok(buf) => {
-                                a = buf;
-                                &mut *a
-                            }
-                            _ => {
-                                b = String::new();
-                                &mut b
-                            }
-                        };
-
-                        if self
-                            .fmt_event
-                            .format_event(
-                                &fmt_ctx,
-                                format::Writer::new(&mut buf).with_ansi(self.is_ansi),
-                                &event,
-                            )
-                            .is_ok()
-                        {
-                            let mut writer = self.make_writer.make_writer_for(&event.metadata());
-                            let res = io::Write::write_all(&mut writer, buf.as_bytes());
-                            if self.log_internal_errors {
-                                if let Err(e) = res {
-                                    eprintln!("[better-tracing] Unable to write an event to the Writer for this Subscriber! Error: {}\n", e);
-                                }
-                            }
-                        } else if self.log_internal_errors {
-                            let err_msg = format!("Unable to format the following event. Name: {}; Fields: {:?}\n",
-                                event.metadata().name(), event.fields());
-                            let mut writer = self.make_writer.make_writer_for(&event.metadata());
-                            let res = io::Write::write_all(&mut writer, err_msg.as_bytes());
-                            if let Err(e) = res {
-                                eprintln!("[better-tracing] Unable to write an \"event formatting error\" to the Writer for this Subscriber! Error: {}\n", e);
-                            }
-                        }
-
-                        buf.clear();
-                    });
+                    self.emit_event(&fmt_ctx, &event);
                     drop(span); // Drop span at the end as originally intended
                 });
             }
@@ -999,81 +1374,77 @@ where
                     mut idle,
                     last,
                     entered_count,
+                    enter_total,
+                    max_busy,
                 } = *timing;
                 debug_assert_eq!(entered_count, 0);
                 idle += (Instant::now() - last).as_nanos() as u64;
 
-                let t_idle = field::display(TimingDisplay(idle));
-                let t_busy = field::display(TimingDisplay(busy));
+                // Slow-span mode: suppress the close event entirely unless the span
+                // was actually busy for at least the configured threshold.
+                if let Some(min_busy) = self.min_busy {
+                    if Duration::from_nanos(busy) < min_busy {
+                        return;
+                    }
+                }
 
                 let span_id = id.clone(); // Store the span ID for the close context
-                with_event_from_span!(
-                    id,
-                    span,
-                    "message" = "close",
-                    "time.busy" = t_busy,
-                    "time.idle" = t_idle,
-                    |event| {
-                        drop(extensions);
-
-                        // Create FmtContext with exiting span information for external formatters
-                        let fmt_ctx = FmtContext {
-                            ctx: ctx.clone(),
-                            fmt_fields: &self.fmt_fields,
-                            event: &event,
-                            exiting_span: Some(span_id), // Use the cloned span ID
-                        };
-
-                        thread_local! {
-                            static BUF: RefCell<String> = const { RefCell::new(String::new()) };
-                        }
 
-                        BUF.with(|buf| {
-                            let borrow = buf.try_borrow_mut();
-                            let mut a;
-                            let mut b;
-                            let mut buf = match borrow {
-                                Ok(buf) => {
-                                    a = buf;
-                                    &mut *a
-                                }
-                                _ => {
-                                    b = String::new();
-                                    &mut b
-                                }
+                // Structured formatters (e.g. JSON) want plain numbers they can aggregate
+                // without parsing a unit suffix; text formatters want the friendly string.
+                if self.numeric_timings {
+                    with_event_from_span!(
+                        id,
+                        span,
+                        "message" = "close",
+                        "time.busy" = busy,
+                        "time.idle" = idle,
+                        "time.busy.max" = max_busy,
+                        "span.enters" = enter_total,
+                        |event| {
+                            drop(extensions);
+
+                            // Create FmtContext with exiting span information for external formatters
+                            let fmt_ctx = FmtContext {
+                                ctx: ctx.clone(),
+                                fmt_fields: &self.fmt_fields,
+                                event: &event,
+                                exiting_span: Some(span_id), // Use the cloned span ID
                             };
 
-                            if self
-                                .fmt_event
-                                .format_event(
-                                    &fmt_ctx,
-                                    format::Writer::new(&mut buf).with_ansi(self.is_ansi),
-                                    &event,
-                                )
-                                .is_ok()
-                            {
-                                let mut writer = self.make_writer.make_writer_for(&event.metadata());
-                                let res = io::Write::write_all(&mut writer, buf.as_bytes());
-                                if self.log_internal_errors {
-                                    if let Err(e) = res {
-                                        eprintln!("[better-tracing] Unable to write an event to the Writer for this Subscriber! Error: {}\n", e);
-                                    }
-                                }
-                            } else if self.log_internal_errors {
-                                let err_msg = format!("Unable to format the following event. Name: {}; Fields: {:?}\n",
-                                    event.metadata().name(), event.fields());
-                                let mut writer = self.make_writer.make_writer_for(&event.metadata());
-                                let res = io::Write::write_all(&mut writer, err_msg.as_bytes());
-                                if let Err(e) = res {
-                                    eprintln!("[better-tracing] Unable to write an \"event formatting error\" to the Writer for this Subscriber! Error: {}\n", e);
-                                }
-                            }
-
-                            buf.clear();
-                        });
-                        drop(span); // Drop span at the end as originally intended
-                    }
-                );
+                            self.emit_event(&fmt_ctx, &event);
+                            drop(span); // Drop span at the end as originally intended
+                        }
+                    );
+                } else {
+                    let t_idle = field::display(TimingDisplay(idle));
+                    let t_busy = field::display(TimingDisplay(busy));
+                    let t_busy_max = field::display(TimingDisplay(max_busy));
+
+                    with_event_from_span!(
+                        id,
+                        span,
+                        "message" = "close",
+                        "time.busy" = t_busy,
+                        "time.idle" = t_idle,
+                        "time.busy.max" = t_busy_max,
+                        "span.enters" = enter_total,
+                        |event| {
+                            drop(extensions);
+
+                            // Create FmtContext with exiting span information for external formatters
+                            let fmt_ctx = FmtContext {
+                                ctx: ctx.clone(),
+                                fmt_fields: &self.fmt_fields,
+                                event: &event,
+                                exiting_span: Some(span_id), // Use the cloned span ID
+                            };
+
+                            self.emit_event(&fmt_ctx, &event);
+                            drop(span); // Drop span at the end as originally intended
+                        }
+                    );
+                }
             } else {
                 let span_id = id.clone(); // Store the span ID for the close context
                 with_event_from_span!(id, span, "message" = "close", |event| {
@@ -1087,53 +1458,7 @@ where
                         exiting_span: Some(span_id), // Use the cloned span ID
                     };
 
-                    thread_local! {
-                        static BUF: RefCell<String> = const { RefCell::new(String::new()) };
-                    }
-
-                    BUF.with(|buf| {
-                        let borrow = buf.try_borrow_mut();
-                        let mut a;
-                        let mut b;
-                        let mut buf = match borrow {
-                            Ok(buf) => {
-                                a = buf;
-                                &mut *a
-                            }
-                            _ => {
-                                b = String::new();
-                                &mut b
-                            }
-                        };
-
-                        if self
-                            .fmt_event
-                            .format_event(
-                                &fmt_ctx,
-                                format::Writer::new(&mut buf).with_ansi(self.is_ansi),
-                                &event,
-                            )
-                            .is_ok()
-                        {
-                            let mut writer = self.make_writer.make_writer_for(&event.metadata());
-                            let res = io::Write::write_all(&mut writer, buf.as_bytes());
-                            if self.log_internal_errors {
-                                if let Err(e) = res {
-                                    eprintln!("[better-tracing] Unable to write an event to the Writer for this Subscriber! Error: {}\n", e);
-                                }
-                            }
-                        } else if self.log_internal_errors {
-                            let err_msg = format!("Unable to format the following event. Name: {}; Fields: {:?}\n",
-                                event.metadata().name(), event.fields());
-                            let mut writer = self.make_writer.make_writer_for(&event.metadata());
-                            let res = io::Write::write_all(&mut writer, err_msg.as_bytes());
-                            if let Err(e) = res {
-                                eprintln!("[better-tracing] Unable to write an \"event formatting error\" to the Writer for this Subscriber! Error: {}\n", e);
-                            }
-                        }
-
-                        buf.clear();
-                    });
+                    self.emit_event(&fmt_ctx, &event);
                     drop(span); // Drop span at the end as originally intended
                 });
             }
@@ -1141,54 +1466,8 @@ where
     }
 
     fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
-        thread_local! {
-            static BUF: RefCell<String> = const { RefCell::new(String::new()) };
-        }
-
-        BUF.with(|buf| {
-            let borrow = buf.try_borrow_mut();
-            let mut a;
-            let mut b;
-            let mut buf = match borrow {
-                Ok(buf) => {
-                    a = buf;
-                    &mut *a
-                }
-                _ => {
-                    b = String::new();
-                    &mut b
-                }
-            };
-
-            let ctx = self.make_ctx(ctx, event);
-            if self
-                .fmt_event
-                .format_event(
-                    &ctx,
-                    format::Writer::new(&mut buf).with_ansi(self.is_ansi),
-                    event,
-                )
-                .is_ok()
-            {
-                let mut writer = self.make_writer.make_writer_for(event.metadata());
-                let res = io::Write::write_all(&mut writer, buf.as_bytes());
-                if self.log_internal_errors {
-                    if let Err(e) = res {
-                        eprintln!("[better-tracing] Unable to write an event to the Writer for this Subscriber! Error: {}\n", e);
-                    }
-                }
-            } else if self.log_internal_errors {
-                let err_msg = format!("Unable to format the following event. Name: {}; Fields: {:?}\n",
-                    event.metadata().name(), event.fields());
-                let mut writer = self.make_writer.make_writer_for(event.metadata());
-                let res = io::Write::write_all(&mut writer, err_msg.as_bytes());
-                if let Err(e) = res {
-                    eprintln!("[better-tracing] Unable to write an \"event formatting error\" to the Writer for this Subscriber! Error: {}\n", e);
-                }
-            }
-
-            buf.clear();
-        });
+        let fmt_ctx = self.make_ctx(ctx, event);
+        self.emit_event(&fmt_ctx, event);
     }
 
     unsafe fn downcast_raw(&self, id: TypeId) -> Option<*const ()> {
@@ -1322,6 +1601,106 @@ where
         }
     }
 
+    /// Returns the `Id` of the span being exited or closed, if the event
+    /// currently being formatted is a synthesized exit/close event.
+    ///
+    /// This is `Some` only while formatting the synthetic events emitted by
+    /// [`Layer::with_span_events`], for the `exit` and `close` span
+    /// lifecycle events; for any other event it is `None`.
+    ///
+    /// [`Layer::with_span_events`]: super::Layer::with_span_events
+    #[inline]
+    pub fn exiting_span_id(&self) -> Option<&Id> {
+        self.exiting_span.as_ref()
+    }
+
+    /// Returns [stored data] for the span being exited or closed, if the
+    /// event currently being formatted is a synthesized exit/close event.
+    ///
+    /// This makes it possible for a custom formatter to pull the closing
+    /// span's own [`FormattedFields`], timing data, metadata, and parent
+    /// scope while rendering its synthetic `exit`/`close` event — something
+    /// [`current_span`] and [`event_scope`] can't provide, since neither
+    /// points at the span that is closing.
+    ///
+    /// Returns `None` for any event other than a synthesized exit/close
+    /// event, or if the span has already been removed from the registry.
+    ///
+    /// [stored data]: SpanRef
+    /// [`current_span`]: Self::current_span
+    /// [`event_scope`]: Self::event_scope
+    #[inline]
+    pub fn exiting_span(&self) -> Option<SpanRef<'_, S>>
+    where
+        S: for<'lookup> LookupSpan<'lookup>,
+    {
+        self.ctx.span(self.exiting_span.as_ref()?)
+    }
+
+    /// Returns a live snapshot of the busy and idle time accumulated so far
+    /// for the span with the given `id`.
+    ///
+    /// Unlike the `time.busy`/`time.idle` fields attached to the
+    /// synthesized `close` event, this can be called while formatting any
+    /// event, not just the close event — for example, to annotate a regular
+    /// event with how long its enclosing span has been busy so far. The
+    /// interval since the span was last entered or exited is folded into
+    /// `busy` (if the span is currently entered) or `idle` (if it isn't),
+    /// so the snapshot is live rather than the last-committed value.
+    ///
+    /// Returns `None` if the span doesn't exist, or if timing isn't being
+    /// tracked for it. Timing is only tracked while a `FmtSpan::CLOSE` or
+    /// `FmtSpan::FULL` span event is configured via
+    /// [`Layer::with_span_events`](super::Layer::with_span_events).
+    pub fn timings(&self, id: &Id) -> Option<TimingSnapshot>
+    where
+        S: for<'lookup> LookupSpan<'lookup>,
+    {
+        let span = self.ctx.span(id)?;
+        let extensions = span.extensions();
+        let timing = extensions.get::<Timings>()?;
+        let Timings {
+            mut busy,
+            mut idle,
+            last,
+            entered_count,
+            ..
+        } = *timing;
+
+        let elapsed = (Instant::now() - last).as_nanos() as u64;
+        if entered_count > 0 {
+            busy += elapsed;
+        } else {
+            idle += elapsed;
+        }
+
+        Some(TimingSnapshot {
+            busy: Duration::from_nanos(busy),
+            idle: Duration::from_nanos(idle),
+            entered_count,
+        })
+    }
+
+    /// Returns the [`Instant`] at which the span with the given `id` was
+    /// created.
+    ///
+    /// Unlike [`timings`](Self::timings), which is only available while
+    /// close-event timing is tracked and splits the span's lifetime into busy
+    /// and idle time, this is recorded for every span and gives its wall-clock age
+    /// and can be used to compute elapsed-since-open offsets for formatters
+    /// that want to render a request/transaction timeline. Passing
+    /// [`exiting_span_id`](Self::exiting_span_id) (or the root span from
+    /// [`root_span`](Self::root_span)) keeps this readable during close
+    /// events.
+    pub fn span_opened_at(&self, id: &Id) -> Option<Instant>
+    where
+        S: for<'lookup> LookupSpan<'lookup>,
+    {
+        let span = self.ctx.span(id)?;
+        let extensions = span.extensions();
+        extensions.get::<SpanOpenedAt>().map(|opened_at| opened_at.0)
+    }
+
     /// Returns an iterator over the spans in the current context.
     ///
     /// For exit/close events, this uses the exiting span as the starting point,
@@ -1345,6 +1724,38 @@ where
         }
     }
 
+    /// Returns an iterator over the [stored data] for all the spans in the
+    /// current context, starting with the root of the trace tree and ending
+    /// with the current span.
+    ///
+    /// For exit/close events, this uses the exiting span as the starting
+    /// point, the same way [`scope`](Self::scope) does.
+    ///
+    /// [stored data]: crate::registry::SpanRef
+    pub fn scope_from_root(&self) -> Option<impl Iterator<Item = SpanRef<'_, S>>>
+    where
+        S: for<'lookup> LookupSpan<'lookup>,
+    {
+        Some(self.scope()?.from_root())
+    }
+
+    /// Returns [stored data] for the root span of the trace tree containing
+    /// the span or event currently being formatted.
+    ///
+    /// For exit/close events, this walks up from the exiting span rather
+    /// than from the event's own context, so the root is still reachable
+    /// while a span is closing.
+    ///
+    /// Returns `None` if there is no current span context.
+    ///
+    /// [stored data]: crate::registry::SpanRef
+    pub fn root_span(&self) -> Option<SpanRef<'_, S>>
+    where
+        S: for<'lookup> LookupSpan<'lookup>,
+    {
+        self.scope()?.from_root().next()
+    }
+
     /// Returns the current span for this formatter.
     pub fn current_span(&self) -> Current {
         self.ctx.current_span()
@@ -1438,11 +1849,36 @@ where
     }
 }
 
+/// A snapshot of a span's accumulated busy and idle time, as returned by
+/// [`FmtContext::timings`].
+#[derive(Debug, Clone, Copy)]
+pub struct TimingSnapshot {
+    /// The total time the span has spent entered so far.
+    pub busy: Duration,
+    /// The total time the span has existed but not been entered so far.
+    pub idle: Duration,
+    /// The number of times the span is currently entered. Normally `0` or
+    /// `1`, but may be higher if the same span is entered concurrently on
+    /// more than one thread.
+    pub entered_count: u64,
+}
+
+/// The [`Instant`] a span was created, stored as a span extension so that a
+/// span's true wall-clock age can be recovered regardless of how much time
+/// it has actually spent entered.
+struct SpanOpenedAt(Instant);
+
 struct Timings {
     idle: u64,
     busy: u64,
     last: Instant,
     entered_count: u64,
+    // Total number of times the span has been entered (0 -> 1 transitions of
+    // `entered_count`), never decremented. Distinguishes a span that's cheap
+    // per-poll but polled thousands of times from one with a single long poll.
+    enter_total: u64,
+    // The longest single busy interval (one enter/exit pair) observed so far.
+    max_busy: u64,
 }
 
 impl Timings {
@@ -1452,6 +1888,8 @@ impl Timings {
             busy: 0,
             last: Instant::now(),
             entered_count: 0,
+            enter_total: 0,
+            max_busy: 0,
         }
     }
 }
@@ -10,15 +10,16 @@ use crate::{
 use serde::ser::{SerializeMap, Serializer as _};
 use serde_json::Serializer;
 use std::{
+    borrow::Cow,
     collections::BTreeMap,
     fmt::{self, Write},
+    sync::Arc,
 };
 use tracing_core::{
     field::{self, Field},
     span::Record,
     Event, Subscriber,
 };
-use tracing_serde::AsSerde;
 
 #[cfg(feature = "tracing-log")]
 use tracing_log::NormalizeEvent;
@@ -65,6 +66,10 @@ use tracing_log::NormalizeEvent;
 ///   span
 /// - [`Json::with_span_list`] can be used to control logging of the span list
 ///   object.
+/// - [`Json::with_timestamp_key`], [`Json::with_level_key`], [`Json::with_target_key`], and
+///   [`Json::with_fields_key`] can be used to remap individual top-level keys, and
+///   [`Json::ecs`]/[`Json::gcp`] apply presets matching Elastic Common Schema and Google
+///   Cloud Logging's structured-payload conventions, respectively.
 ///
 /// By default, event fields are not flattened, and both current span and span
 /// list are logged.
@@ -83,14 +88,30 @@ use tracing_log::NormalizeEvent;
 /// [`Json::flatten_event`]: Json::flatten_event()
 /// [`Json::with_current_span`]: Json::with_current_span()
 /// [`Json::with_span_list`]: Json::with_span_list()
+/// [`Json::with_timestamp_key`]: Json::with_timestamp_key()
+/// [`Json::with_level_key`]: Json::with_level_key()
+/// [`Json::with_target_key`]: Json::with_target_key()
+/// [`Json::with_fields_key`]: Json::with_fields_key()
+/// [`Json::ecs`]: Json::ecs()
+/// [`Json::gcp`]: Json::gcp()
 /// [`valuable`]: https://crates.io/crates/valuable
 /// [unstable]: crate#unstable-features
 /// [`valuable::Valuable`]: https://docs.rs/valuable/latest/valuable/trait.Valuable.html
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Json {
     pub(crate) flatten_event: bool,
     pub(crate) display_current_span: bool,
     pub(crate) display_span_list: bool,
+    pub(crate) timestamp_key: String,
+    pub(crate) level_key: String,
+    pub(crate) message_key: String,
+    pub(crate) target_key: String,
+    pub(crate) fields_key: String,
+    pub(crate) level_format: LevelFormat,
+    pub(crate) collision: FieldCollision,
+    pub(crate) pretty: bool,
+    pub(crate) static_fields: Arc<serde_json::Map<String, serde_json::Value>>,
+    pub(crate) flatten_span_fields_prefix: Option<String>,
 }
 
 impl Json {
@@ -109,6 +130,239 @@ impl Json {
     pub fn with_span_list(&mut self, display_span_list: bool) {
         self.display_span_list = display_span_list;
     }
+
+    /// Renames the `"timestamp"` key the formatted object uses, e.g. to match an existing
+    /// ingestion schema that expects `"time"`.
+    pub fn with_timestamp_key(&mut self, key: impl Into<String>) {
+        self.timestamp_key = key.into();
+    }
+
+    /// Renames the `"level"` key the formatted object uses.
+    pub fn with_level_key(&mut self, key: impl Into<String>) {
+        self.level_key = key.into();
+    }
+
+    /// Renames the event's `"message"` field when [`flatten_event`](Self::flatten_event) is
+    /// enabled, e.g. to match an existing ingestion schema that expects `"msg"`.
+    ///
+    /// Has no effect when event fields are nested under `"fields"` instead of flattened,
+    /// since the message field there keeps its recorded name like any other field.
+    pub fn with_message_key(&mut self, key: impl Into<String>) {
+        self.message_key = key.into();
+    }
+
+    /// Chooses how the event's level is encoded; see [`LevelFormat`]. Defaults to
+    /// [`LevelFormat::UpperCase`], matching `tracing`'s own `Level` display form.
+    pub fn with_level_format(&mut self, level_format: LevelFormat) {
+        self.level_format = level_format;
+    }
+
+    /// Chooses how a [`flatten_event`](Self::flatten_event)ed field that collides with one
+    /// of the object's built-in keys (the timestamp/level keys, `target`, `span`, `spans`,
+    /// `filename`, `line_number`, `threadName`, `threadId`) is resolved; see
+    /// [`FieldCollision`]. Defaults to [`FieldCollision::KeepLast`].
+    pub fn with_field_collision(&mut self, collision: FieldCollision) {
+        self.collision = collision;
+    }
+
+    /// Renames the `"target"` key the formatted object uses.
+    pub fn with_target_key(&mut self, key: impl Into<String>) {
+        self.target_key = key.into();
+    }
+
+    /// Renames the `"fields"` key that event fields are nested under when
+    /// [`flatten_event`](Self::flatten_event) is disabled, e.g. to match Google Cloud
+    /// Logging's `"jsonPayload"` convention.
+    ///
+    /// Has no effect when event fields are flattened into the root object, since there's no
+    /// longer a wrapping object to rename.
+    pub fn with_fields_key(&mut self, key: impl Into<String>) {
+        self.fields_key = key.into();
+    }
+
+    /// Configures the timestamp, level, and target keys to match the [Elastic Common
+    /// Schema] convention: `"@timestamp"`, `"log.level"` (lowercased), and `"log.logger"`.
+    ///
+    /// [Elastic Common Schema]: https://www.elastic.co/guide/en/ecs/current/index.html
+    pub fn ecs(&mut self) {
+        self.timestamp_key = "@timestamp".to_string();
+        self.level_key = "log.level".to_string();
+        self.level_format = LevelFormat::LowerCase;
+        self.target_key = "log.logger".to_string();
+    }
+
+    /// Configures the timestamp and level keys, and the key event fields are nested under,
+    /// to match the [Google Cloud Logging] structured payload convention: `"time"`,
+    /// `"severity"`, and `"jsonPayload"`.
+    ///
+    /// [Google Cloud Logging]: https://cloud.google.com/logging/docs/structured-logging
+    pub fn gcp(&mut self) {
+        self.timestamp_key = "time".to_string();
+        self.level_key = "severity".to_string();
+        self.fields_key = "jsonPayload".to_string();
+    }
+
+    /// If set to `true`, each record is indented across multiple lines instead of written as
+    /// compact single-line JSON, for easier reading during local development.
+    ///
+    /// Records remain unambiguously delimited: each indented record is followed by a blank
+    /// line, rather than relying on every record occupying exactly one line.
+    pub fn pretty(&mut self, pretty: bool) {
+        self.pretty = pretty;
+    }
+
+    /// Injects `fields` into the root object of every formatted record, alongside the
+    /// timestamp and level — e.g. `service.name`, `version`, or `environment`, to attach
+    /// deployment metadata without repeating it in every `info!` call.
+    ///
+    /// Static fields are written first, before the timestamp, level, and event fields, so a
+    /// field recorded on an event (or a built-in key) with the same name simply overrides
+    /// it: most JSON parsers, including `serde_json`, keep only the last occurrence of a
+    /// duplicate key.
+    pub fn with_static_fields(
+        &mut self,
+        fields: impl IntoIterator<Item = (String, serde_json::Value)>,
+    ) {
+        self.static_fields = Arc::new(fields.into_iter().collect());
+    }
+
+    /// Flattens every ancestor span's fields into the root object, as `"<prefix><span
+    /// name>.<field>"`, instead of (or alongside) the nested [`with_current_span`]/
+    /// [`with_span_list`] representations — many JSON ingestion systems can't index nested
+    /// objects or arrays and need flat dotted keys.
+    ///
+    /// Spans are walked root to leaf, so if two ancestor spans share a name and a field, the
+    /// leaf span's value wins, the same way a duplicate JSON key resolves to its last
+    /// occurrence.
+    ///
+    /// [`with_current_span`]: Json::with_current_span
+    /// [`with_span_list`]: Json::with_span_list
+    pub fn flatten_span_fields(&mut self, prefix: impl Into<String>) {
+        self.flatten_span_fields_prefix = Some(prefix.into());
+    }
+}
+
+/// How [`Json`] resolves a [`flatten_event`](Json::flatten_event)ed event field whose name
+/// collides with one of the object's built-in keys, e.g. a field literally named `target`
+/// or `span`.
+///
+/// Without a policy, which value ends up in the final object depends on the order the
+/// formatter happens to write keys in, which is an implementation detail, not a contract;
+/// two fields sharing a key is also ambiguous to downstream JSON readers, most of which
+/// silently keep only the last occurrence. Choosing a policy makes the outcome explicit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FieldCollision {
+    /// The built-in key wins; the colliding event field is dropped.
+    KeepFirst,
+    /// The event field wins; the built-in key is omitted for this record. This is the
+    /// default.
+    KeepLast,
+    /// Both are kept: the event field is renamed to `field.<name>`, leaving the built-in
+    /// key untouched.
+    Prefix,
+}
+
+/// How [`Json`] encodes an event's [`Level`](tracing_core::Level) in the output object.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LevelFormat {
+    /// Serializes the level as an uppercase string, e.g. `"INFO"`. This is the default,
+    /// matching `tracing`'s own `Level` display form.
+    UpperCase,
+    /// Serializes the level as a lowercase string, e.g. `"info"`.
+    LowerCase,
+    /// Serializes the level as the numeric syslog-style severity node-bunyan uses
+    /// (ERROR→50, WARN→40, INFO→30, DEBUG→20, TRACE→10).
+    Numeric,
+}
+
+impl LevelFormat {
+    fn format(self, level: &tracing_core::Level) -> serde_json::Value {
+        match self {
+            LevelFormat::UpperCase => serde_json::Value::from(level.to_string()),
+            LevelFormat::LowerCase => serde_json::Value::from(level.to_string().to_lowercase()),
+            LevelFormat::Numeric => serde_json::Value::from(bunyan_level(level)),
+        }
+    }
+}
+
+/// How a byte-slice field (recorded with [`field::Visit::record_bytes`]) is encoded into a
+/// [`serde_json::Value`].
+///
+/// [`field::Visit::record_bytes`]: crate::field::Visit::record_bytes
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum BytesEncoding {
+    /// Serializes the bytes as a JSON array of numbers, e.g. `[97,98,99]`. This is the
+    /// default, matching the encoding `better-tracing` has always used.
+    #[default]
+    Array,
+    /// Serializes the bytes as a string, replacing any invalid UTF-8 with the replacement
+    /// character. Lossy and irreversible, but readable, for fields that are usually (but not
+    /// guaranteed to be) text.
+    Utf8Lossy,
+    /// Serializes the bytes as a standard base64 string, compact and reversible for
+    /// arbitrary binary data.
+    Base64,
+}
+
+impl BytesEncoding {
+    fn encode(self, bytes: &[u8]) -> serde_json::Value {
+        match self {
+            BytesEncoding::Array => serde_json::Value::from(bytes),
+            BytesEncoding::Utf8Lossy => {
+                serde_json::Value::from(String::from_utf8_lossy(bytes).into_owned())
+            }
+            BytesEncoding::Base64 => serde_json::Value::from(base64_encode(bytes)),
+        }
+    }
+}
+
+/// A minimal standard (RFC 4648, with padding) base64 encoder, so [`BytesEncoding::Base64`]
+/// doesn't need to pull in a dedicated crate for what's otherwise a handful of lines.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[((b0 & 0x03) << 4 | b1.unwrap_or(0) >> 4) as usize] as char);
+        out.push(match b1 {
+            Some(b1) => ALPHABET[((b1 & 0x0f) << 2 | b2.unwrap_or(0) >> 6) as usize] as char,
+            None => '=',
+        });
+        out.push(match b2 {
+            Some(b2) => ALPHABET[(b2 & 0x3f) as usize] as char,
+            None => '=',
+        });
+    }
+    out
+}
+
+/// Wraps another [`JsonFieldMapper`], overriding only how byte-slice fields are encoded; see
+/// [`JsonFields::with_bytes_encoding`].
+#[derive(Debug)]
+struct BytesEncodingMapper {
+    inner: Arc<dyn JsonFieldMapper>,
+    encoding: BytesEncoding,
+}
+
+impl JsonFieldMapper for BytesEncodingMapper {
+    fn map_field(
+        &self,
+        field: &Field,
+        value: JsonFieldValue<'_>,
+    ) -> (Cow<'static, str>, serde_json::Value) {
+        match value {
+            JsonFieldValue::Bytes(bytes) => {
+                let (key, _) = self.inner.map_field(field, JsonFieldValue::Bytes(bytes));
+                (key, self.encoding.encode(bytes))
+            }
+            other => self.inner.map_field(field, other),
+        }
+    }
 }
 
 struct SerializableContext<'a, 'b, Span, N>(
@@ -161,15 +415,23 @@ where
         let mut serializer = serializer.serialize_map(None)?;
 
         let ext = self.0.extensions();
+
+        if let Some(map) = ext.get::<JsonFieldsMap>() {
+            for (k, v) in &map.0 {
+                serializer.serialize_entry(k, v)?;
+            }
+            serializer.serialize_entry("name", self.0.metadata().name())?;
+            return serializer.end();
+        }
+
         let data = ext
             .get::<FormattedFields<N>>()
             .expect("Unable to find FormattedFields in extensions; this is a bug");
 
-        // TODO: let's _not_ do this, but this resolves
-        // https://github.com/tokio-rs/tracing/issues/391.
-        // We should probably rework this to use a `serde_json::Value` or something
-        // similar in a JSON-specific layer, but I'd (david)
-        // rather have a uglier fix now rather than shipping broken JSON.
+        // This fallback only runs when `N` isn't `JsonFields`, so there's no `JsonFieldsMap`
+        // to read from directly above. It resolves
+        // https://github.com/tokio-rs/tracing/issues/391 the uglier way: parsing the
+        // formatter's own string output back into JSON rather than shipping broken JSON.
         match serde_json::from_str::<serde_json::Value>(data) {
             Ok(serde_json::Value::Object(fields)) => {
                 for field in fields {
@@ -235,22 +497,22 @@ where
         #[cfg(not(feature = "tracing-log"))]
         let meta = event.metadata();
 
+        let mut buf = String::new();
+
         let mut visit = || {
-            let mut serializer = Serializer::new(WriteAdaptor::new(&mut writer));
+            let mut serializer = Serializer::new(WriteAdaptor::new(&mut buf));
 
             let mut serializer = serializer.serialize_map(None)?;
 
-            if self.display_timestamp {
-                serializer.serialize_entry("timestamp", &timestamp)?;
-            }
-
-            if self.display_level {
-                serializer.serialize_entry("level", &meta.level().as_serde())?;
+            for (k, v) in self.format.static_fields.iter() {
+                serializer.serialize_entry(k, v)?;
             }
 
             let format_field_marker: std::marker::PhantomData<N> = std::marker::PhantomData;
 
-            let current_span = if self.format.display_current_span || self.format.display_span_list
+            let current_span = if self.format.display_current_span
+                || self.format.display_span_list
+                || self.format.flatten_span_fields_prefix.is_some()
             {
                 event
                     .parent()
@@ -260,33 +522,92 @@ where
                 None
             };
 
-            if self.format.flatten_event {
-                let mut visitor = tracing_serde::SerdeMapVisitor::new(serializer);
+            // When flattening event fields into the root object, a field whose name
+            // collides with one of the built-in keys below (the timestamp/level keys,
+            // `target`, `span`, ...) would otherwise be written twice, with whichever write
+            // happened to land last silently winning. Resolve that up front, according to
+            // `self.format.collision`, rather than leaving it to write order.
+            let flattened_fields = self.format.flatten_event.then(|| {
+                let mut buf = String::new();
+                let mut visitor = JsonVisitor::with_mapper(&mut buf, mapper_of(ctx.fmt_fields));
                 event.record(&mut visitor);
 
-                serializer = visitor.take_serializer()?;
+                let reserved: &[&str] = &[
+                    &self.format.timestamp_key,
+                    &self.format.level_key,
+                    &self.format.target_key,
+                    "filename",
+                    "line_number",
+                    "span",
+                    "spans",
+                    "threadName",
+                    "threadId",
+                ];
+
+                let mut fields = Vec::with_capacity(visitor.values.len());
+                for (k, v) in visitor.values {
+                    let key = if k == "message" {
+                        self.format.message_key.clone()
+                    } else {
+                        k
+                    };
+                    if reserved.contains(&key.as_str()) {
+                        match self.format.collision {
+                            FieldCollision::KeepFirst => continue,
+                            FieldCollision::KeepLast => fields.push((key, v)),
+                            FieldCollision::Prefix => fields.push((format!("field.{}", key), v)),
+                        }
+                    } else {
+                        fields.push((key, v));
+                    }
+                }
+                fields
+            });
+
+            let is_suppressed = |key: &str| {
+                self.format.collision == FieldCollision::KeepLast
+                    && flattened_fields
+                        .as_ref()
+                        .is_some_and(|fields| fields.iter().any(|(k, _)| k == key))
+            };
+
+            if self.display_timestamp && !is_suppressed(&self.format.timestamp_key) {
+                serializer.serialize_entry(&self.format.timestamp_key, &timestamp)?;
+            }
+
+            if self.display_level && !is_suppressed(&self.format.level_key) {
+                serializer.serialize_entry(
+                    &self.format.level_key,
+                    &self.format.level_format.format(meta.level()),
+                )?;
+            }
+
+            if let Some(ref fields) = flattened_fields {
+                for (k, v) in fields {
+                    serializer.serialize_entry(k, v)?;
+                }
             } else {
                 use tracing_serde::fields::AsMap;
-                serializer.serialize_entry("fields", &event.field_map())?;
+                serializer.serialize_entry(&self.format.fields_key, &event.field_map())?;
             };
 
-            if self.display_target {
-                serializer.serialize_entry("target", meta.target())?;
+            if self.display_target && !is_suppressed(&self.format.target_key) {
+                serializer.serialize_entry(&self.format.target_key, meta.target())?;
             }
 
-            if self.display_filename {
+            if self.display_filename && !is_suppressed("filename") {
                 if let Some(filename) = meta.file() {
                     serializer.serialize_entry("filename", filename)?;
                 }
             }
 
-            if self.display_line_number {
+            if self.display_line_number && !is_suppressed("line_number") {
                 if let Some(line_number) = meta.line() {
                     serializer.serialize_entry("line_number", &line_number)?;
                 }
             }
 
-            if self.format.display_current_span {
+            if self.format.display_current_span && !is_suppressed("span") {
                 if let Some(ref span) = current_span {
                     serializer
                         .serialize_entry("span", &SerializableSpan(span, format_field_marker))
@@ -294,14 +615,25 @@ where
                 }
             }
 
-            if self.format.display_span_list && current_span.is_some() {
+            if self.format.display_span_list && !is_suppressed("spans") && current_span.is_some() {
                 serializer.serialize_entry(
                     "spans",
                     &SerializableContext(&ctx.ctx, format_field_marker),
                 )?;
             }
 
-            if self.display_thread_name {
+            if let Some(ref prefix) = self.format.flatten_span_fields_prefix {
+                if let Some(ref leaf_span) = current_span {
+                    for span in leaf_span.scope().from_root() {
+                        let name = span.metadata().name();
+                        for (k, v) in span_fields_as_map::<S, N>(&span) {
+                            serializer.serialize_entry(&format!("{prefix}{name}.{k}"), &v)?;
+                        }
+                    }
+                }
+            }
+
+            if self.display_thread_name && !is_suppressed("threadName") {
                 let current_thread = std::thread::current();
                 match current_thread.name() {
                     Some(name) => {
@@ -316,7 +648,7 @@ where
                 }
             }
 
-            if self.display_thread_id {
+            if self.display_thread_id && !is_suppressed("threadId") {
                 serializer
                     .serialize_entry("threadId", &format!("{:?}", std::thread::current().id()))?;
             }
@@ -325,7 +657,22 @@ where
         };
 
         visit().map_err(|_| fmt::Error)?;
-        writeln!(writer)
+
+        if self.format.pretty {
+            // Re-parse and pretty-print the record we just serialized compactly. This is
+            // wasteful, but `pretty` is meant for local development and debugging, not the
+            // high-throughput path, so simplicity wins over avoiding the extra pass.
+            let value: serde_json::Value = serde_json::from_str(&buf).map_err(|_| fmt::Error)?;
+            let pretty = serde_json::to_string_pretty(&value).map_err(|_| fmt::Error)?;
+            writer.write_str(&pretty)?;
+            // A blank line delimits each record unambiguously, since the indented record
+            // itself now spans multiple `\n`-separated lines.
+            writeln!(writer)?;
+            writeln!(writer)
+        } else {
+            writer.write_str(&buf)?;
+            writeln!(writer)
+        }
     }
 }
 
@@ -335,24 +682,338 @@ impl Default for Json {
             flatten_event: false,
             display_current_span: true,
             display_span_list: true,
+            timestamp_key: "timestamp".to_string(),
+            level_key: "level".to_string(),
+            message_key: "message".to_string(),
+            target_key: "target".to_string(),
+            fields_key: "fields".to_string(),
+            level_format: LevelFormat::UpperCase,
+            collision: FieldCollision::KeepLast,
+            pretty: false,
+            static_fields: Arc::new(serde_json::Map::new()),
+            flatten_span_fields_prefix: None,
         }
     }
 }
 
+/// Bunyan's fixed top-level keys, which a user field is never allowed to overwrite when
+/// spans and event fields are flattened into the root object.
+const BUNYAN_RESERVED_KEYS: &[&str] = &["v", "level", "name", "hostname", "pid", "time", "msg"];
+
+/// Maps a [`tracing_core::Level`] to the numeric severity [node-bunyan] log records use.
+///
+/// [node-bunyan]: https://github.com/trentm/node-bunyan
+fn bunyan_level(level: &tracing_core::Level) -> u16 {
+    match *level {
+        tracing_core::Level::ERROR => 50,
+        tracing_core::Level::WARN => 40,
+        tracing_core::Level::INFO => 30,
+        tracing_core::Level::DEBUG => 20,
+        tracing_core::Level::TRACE => 10,
+    }
+}
+
+/// Best-effort local hostname lookup without pulling in a platform-specific dependency.
+fn local_hostname() -> String {
+    std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Marker for [`Format`] that indicates that [node-bunyan]-compatible newline-delimited
+/// JSON should be used.
+///
+/// Every record carries bunyan's core fields — `v`, `name`, `hostname`, `pid`, `time`,
+/// `msg`, and a numeric `level` (ERROR→50, WARN→40, INFO→30, DEBUG→20, TRACE→10) — with the
+/// event's own fields and the fields of every currently entered span (root to leaf)
+/// flattened into the same root object, rather than nested under `"fields"`/`"spans"` the
+/// way [`Json`] does. A user field that collides with one of bunyan's reserved keys is
+/// dropped rather than overwriting it; among colliding user fields, the most specific wins
+/// (a leaf span's field beats an ancestor's, and the event's own field beats any span's).
+///
+/// [node-bunyan]: https://github.com/trentm/node-bunyan
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Bunyan {
+    name: String,
+    include_pid: bool,
+    include_hostname: bool,
+}
+
+impl Bunyan {
+    /// Returns a new Bunyan formatter marker that reports `name` as the record's `"name"`
+    /// field, the way node-bunyan identifies which service emitted a log line.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            include_pid: true,
+            include_hostname: true,
+        }
+    }
+
+    /// If set to `false`, records won't contain a `"pid"` field.
+    pub fn with_pid(&mut self, include_pid: bool) {
+        self.include_pid = include_pid;
+    }
+
+    /// If set to `false`, records won't contain a `"hostname"` field.
+    pub fn with_hostname(&mut self, include_hostname: bool) {
+        self.include_hostname = include_hostname;
+    }
+}
+
+impl Default for Bunyan {
+    fn default() -> Self {
+        Self::new("better-tracing")
+    }
+}
+
+/// JSON-native storage for a span's recorded fields, kept in the span's extensions
+/// alongside its [`FormattedFields<JsonFields>`] string.
+///
+/// `JsonFields`'s [`FormatFields`] impl still writes that `FormattedFields` string, for any
+/// formatter other than [`Json`]/[`Bunyan`] that expects it, but [`SerializableSpan`] and
+/// [`span_fields_as_map`] read this map instead: recording fields on a span repeatedly, or
+/// rendering a deep span stack on every event, no longer means parsing the same JSON text
+/// back out of `FormattedFields` just to read or extend it.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct JsonFieldsMap(serde_json::Map<String, serde_json::Value>);
+
+impl JsonFields {
+    /// Records `fields` into a fresh [`JsonFieldsMap`], for a span's first set of fields.
+    pub(crate) fn record_new<R: RecordFields>(&self, fields: R) -> JsonFieldsMap {
+        let mut map = JsonFieldsMap::default();
+        self.record_into(&mut map, fields);
+        map
+    }
+
+    /// Records `fields` into an already-populated [`JsonFieldsMap`], overwriting any field
+    /// it shares a name with, the same way [`FormatFields::add_fields`] does for the string
+    /// representation.
+    pub(crate) fn record_into<R: RecordFields>(&self, map: &mut JsonFieldsMap, fields: R) {
+        let mut buf = String::new();
+        let mut visitor = JsonVisitor::with_mapper(&mut buf, self.mapper.as_ref());
+        fields.record(&mut visitor);
+        map.0.extend(visitor.values);
+    }
+}
+
+/// Returns a span's recorded fields as a JSON object map, preferring the JSON-native
+/// [`JsonFieldsMap`] extension when present and otherwise falling back to parsing the JSON
+/// object a span's [`JsonFields`] formatter previously wrote into its [`FormattedFields`]
+/// extension, so callers merging several spans' fields together (like flattening a whole
+/// scope into one object) don't pay to parse the same string twice.
+fn span_fields_as_map<Span, N>(
+    span: &crate::registry::SpanRef<'_, Span>,
+) -> serde_json::Map<String, serde_json::Value>
+where
+    Span: for<'lookup> crate::registry::LookupSpan<'lookup>,
+    N: for<'writer> FormatFields<'writer> + 'static,
+{
+    let ext = span.extensions();
+
+    if let Some(map) = ext.get::<JsonFieldsMap>() {
+        return map.0.clone();
+    }
+
+    let data = ext
+        .get::<FormattedFields<N>>()
+        .expect("Unable to find FormattedFields in extensions; this is a bug");
+
+    match serde_json::from_str::<serde_json::Value>(data) {
+        Ok(serde_json::Value::Object(fields)) => fields,
+        _ => serde_json::Map::new(),
+    }
+}
+
+impl<S, N, T> FormatEvent<S, N> for Format<Bunyan, T>
+where
+    S: Subscriber + for<'lookup> LookupSpan<'lookup>,
+    N: for<'writer> FormatFields<'writer> + 'static,
+    T: FormatTime,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let mut time = String::new();
+        self.timer.format_time(&mut Writer::new(&mut time))?;
+
+        let meta = event.metadata();
+
+        // Gather span fields root-to-leaf, then the event's own fields, so the most
+        // specific context wins any field-name collision.
+        let mut extra = serde_json::Map::new();
+        if let Some(leaf_span) = ctx.lookup_current() {
+            for span in leaf_span.scope().from_root() {
+                for (k, v) in span_fields_as_map::<S, N>(&span) {
+                    if !BUNYAN_RESERVED_KEYS.contains(&k.as_str()) {
+                        extra.insert(k, v);
+                    }
+                }
+            }
+        }
+
+        let mut event_fields = {
+            let mut buf = String::new();
+            let mut visitor = JsonVisitor::with_mapper(&mut buf, mapper_of(ctx.fmt_fields));
+            event.record(&mut visitor);
+            visitor.values
+        };
+        let msg = event_fields
+            .remove("message")
+            .unwrap_or_else(|| serde_json::Value::from(""));
+        for (k, v) in event_fields {
+            if !BUNYAN_RESERVED_KEYS.contains(&k.as_str()) {
+                extra.insert(k, v);
+            }
+        }
+
+        let mut visit = || {
+            let mut serializer = Serializer::new(WriteAdaptor::new(&mut writer));
+            let mut serializer = serializer.serialize_map(None)?;
+
+            serializer.serialize_entry("v", &0u8)?;
+            serializer.serialize_entry("name", &self.format.name)?;
+            if self.format.include_hostname {
+                serializer.serialize_entry("hostname", &local_hostname())?;
+            }
+            if self.format.include_pid {
+                serializer.serialize_entry("pid", &std::process::id())?;
+            }
+            serializer.serialize_entry("time", &time)?;
+            serializer.serialize_entry("msg", &msg)?;
+            serializer.serialize_entry("level", &bunyan_level(meta.level()))?;
+
+            for (k, v) in &extra {
+                serializer.serialize_entry(k, v)?;
+            }
+
+            serializer.end()
+        };
+
+        visit().map_err(|_| fmt::Error)?;
+        writeln!(writer)
+    }
+}
+
+/// A single field's typed value, as passed to a [`JsonFieldMapper`].
+pub enum JsonFieldValue<'a> {
+    /// A double precision floating point value.
+    F64(f64),
+    /// A signed 64-bit integer value.
+    I64(i64),
+    /// An unsigned 64-bit integer value.
+    U64(u64),
+    /// A boolean value.
+    Bool(bool),
+    /// A string value.
+    Str(&'a str),
+    /// A byte slice value.
+    Bytes(&'a [u8]),
+    /// A value recorded via its [`std::fmt::Debug`] implementation.
+    Debug(&'a dyn fmt::Debug),
+}
+
+/// Customizes how a single field recorded on a span or event is encoded into a
+/// [`serde_json::Value`], and under what key.
+///
+/// Register one on a [`JsonFields`] formatter with [`JsonFields::with_field_mapper`] to, for
+/// example, encode byte fields as base64/hex instead of a numeric array, redact fields whose
+/// name matches a pattern, or coerce stringly-typed debug output into real JSON numbers.
+/// [`DefaultJsonFieldMapper`] reproduces the encoding `better-tracing` has always used.
+pub trait JsonFieldMapper: fmt::Debug + Send + Sync {
+    /// Returns the key `field` should be recorded under, and the JSON value `value` should
+    /// take.
+    fn map_field(
+        &self,
+        field: &Field,
+        value: JsonFieldValue<'_>,
+    ) -> (Cow<'static, str>, serde_json::Value);
+}
+
+/// The [`JsonFieldMapper`] used when none is configured.
+///
+/// Numeric and boolean fields map directly to the matching JSON type, strings and byte
+/// slices map to a JSON string/array, and debug values are formatted with `{:?}`. A debug
+/// value recorded under a name with the raw-identifier `r#` prefix (e.g. `r#type`) has the
+/// prefix stripped from its key; fields of the other kinds keep their name as recorded.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultJsonFieldMapper;
+
+impl JsonFieldMapper for DefaultJsonFieldMapper {
+    fn map_field(
+        &self,
+        field: &Field,
+        value: JsonFieldValue<'_>,
+    ) -> (Cow<'static, str>, serde_json::Value) {
+        match value {
+            JsonFieldValue::F64(v) => (Cow::Borrowed(field.name()), serde_json::Value::from(v)),
+            JsonFieldValue::I64(v) => (Cow::Borrowed(field.name()), serde_json::Value::from(v)),
+            JsonFieldValue::U64(v) => (Cow::Borrowed(field.name()), serde_json::Value::from(v)),
+            JsonFieldValue::Bool(v) => (Cow::Borrowed(field.name()), serde_json::Value::from(v)),
+            JsonFieldValue::Str(v) => (Cow::Borrowed(field.name()), serde_json::Value::from(v)),
+            JsonFieldValue::Bytes(v) => (Cow::Borrowed(field.name()), serde_json::Value::from(v)),
+            JsonFieldValue::Debug(v) => {
+                let key = match field.name().strip_prefix("r#") {
+                    Some(name) => Cow::Owned(name.to_string()),
+                    None => Cow::Borrowed(field.name()),
+                };
+                (key, serde_json::Value::from(format!("{:?}", v)))
+            }
+        }
+    }
+}
+
+/// Returns the [`JsonFieldMapper`] configured on `fmt_fields` if it's a [`JsonFields`], or
+/// [`DefaultJsonFieldMapper`] otherwise — e.g. when a non-JSON field formatter is paired with
+/// the [`Json`]/[`Bunyan`] event formatter.
+fn mapper_of<N: 'static>(fmt_fields: &N) -> &dyn JsonFieldMapper {
+    match (fmt_fields as &dyn std::any::Any).downcast_ref::<JsonFields>() {
+        Some(json_fields) => json_fields.mapper.as_ref(),
+        None => &DefaultJsonFieldMapper,
+    }
+}
+
 /// The JSON [`FormatFields`] implementation.
 ///
 #[derive(Debug)]
 pub struct JsonFields {
-    // reserve the ability to add fields to this without causing a breaking
-    // change in the future.
-    _private: (),
+    mapper: Arc<dyn JsonFieldMapper>,
 }
 
 impl JsonFields {
     /// Returns a new JSON [`FormatFields`] implementation.
     ///
     pub fn new() -> Self {
-        Self { _private: () }
+        Self {
+            mapper: Arc::new(DefaultJsonFieldMapper),
+        }
+    }
+
+    /// Registers `mapper` to customize how each recorded field is encoded; see
+    /// [`JsonFieldMapper`].
+    pub fn with_field_mapper(mut self, mapper: impl JsonFieldMapper + 'static) -> Self {
+        self.mapper = Arc::new(mapper);
+        self
+    }
+
+    /// Chooses how byte-slice fields (recorded with `record_bytes`) are encoded; see
+    /// [`BytesEncoding`]. Defaults to [`BytesEncoding::Array`].
+    ///
+    /// Composes with a mapper set via [`with_field_mapper`](Self::with_field_mapper): the
+    /// chosen encoding overrides only byte-slice fields, deferring to the existing mapper for
+    /// everything else (including the key a byte-slice field is recorded under).
+    pub fn with_bytes_encoding(mut self, encoding: BytesEncoding) -> Self {
+        self.mapper = Arc::new(BytesEncodingMapper {
+            inner: self.mapper,
+            encoding,
+        });
+        self
     }
 }
 
@@ -365,7 +1026,7 @@ impl Default for JsonFields {
 impl<'a> FormatFields<'a> for JsonFields {
     /// Format the provided `fields` to the provided `writer`, returning a result.
     fn format_fields<R: RecordFields>(&self, mut writer: Writer<'_>, fields: R) -> fmt::Result {
-        let mut v = JsonVisitor::new(&mut writer);
+        let mut v = JsonVisitor::with_mapper(&mut writer, self.mapper.as_ref());
         fields.record(&mut v);
         v.finish()
     }
@@ -384,7 +1045,7 @@ impl<'a> FormatFields<'a> for JsonFields {
             // If there are no previously recorded fields, we can just reuse the
             // existing string.
             let mut writer = current.as_writer();
-            let mut v = JsonVisitor::new(&mut writer);
+            let mut v = JsonVisitor::with_mapper(&mut writer, self.mapper.as_ref());
             fields.record(&mut v);
             v.finish()?;
             return Ok(());
@@ -405,9 +1066,9 @@ impl<'a> FormatFields<'a> for JsonFields {
         // then, we could store fields as JSON values, and add to them
         // without having to parse and re-serialize.
         let mut new = String::new();
-        let map: BTreeMap<&'_ str, serde_json::Value> =
+        let map: BTreeMap<String, serde_json::Value> =
             serde_json::from_str(current).map_err(|_| fmt::Error)?;
-        let mut v = JsonVisitor::new(&mut new);
+        let mut v = JsonVisitor::with_mapper(&mut new, self.mapper.as_ref());
         v.values = map;
         fields.record(&mut v);
         v.finish()?;
@@ -422,8 +1083,9 @@ impl<'a> FormatFields<'a> for JsonFields {
 /// [visitor]: crate::field::Visit
 /// [`MakeVisitor`]: crate::field::MakeVisitor
 pub struct JsonVisitor<'a> {
-    values: BTreeMap<&'a str, serde_json::Value>,
+    values: BTreeMap<String, serde_json::Value>,
     writer: &'a mut dyn Write,
+    mapper: &'a dyn JsonFieldMapper,
 }
 
 impl fmt::Debug for JsonVisitor<'_> {
@@ -440,9 +1102,16 @@ impl<'a> JsonVisitor<'a> {
     /// - `is_empty`: whether or not any fields have been previously written to
     ///   that writer.
     pub fn new(writer: &'a mut dyn Write) -> Self {
+        Self::with_mapper(writer, &DefaultJsonFieldMapper)
+    }
+
+    /// Returns a new visitor that formats to the provided `writer`, encoding each field
+    /// through `mapper` instead of the default encoding; see [`JsonFieldMapper`].
+    pub fn with_mapper(writer: &'a mut dyn Write, mapper: &'a dyn JsonFieldMapper) -> Self {
         Self {
             values: BTreeMap::new(),
             writer,
+            mapper,
         }
     }
 }
@@ -460,7 +1129,7 @@ impl crate::field::VisitOutput<fmt::Result> for JsonVisitor<'_> {
             let mut ser_map = serializer.serialize_map(None)?;
 
             for (k, v) in self.values {
-                ser_map.serialize_entry(k, &v)?;
+                ser_map.serialize_entry(&k, &v)?;
             }
 
             ser_map.end()
@@ -492,58 +1161,53 @@ impl field::Visit for JsonVisitor<'_> {
             }
         };
 
-        self.values.insert(field.name(), value);
+        self.values.insert(field.name().to_string(), value);
     }
 
     /// Visit a double precision floating point value.
     fn record_f64(&mut self, field: &Field, value: f64) {
-        self.values
-            .insert(field.name(), serde_json::Value::from(value));
+        let (key, value) = self.mapper.map_field(field, JsonFieldValue::F64(value));
+        self.values.insert(key.into_owned(), value);
     }
 
     /// Visit a signed 64-bit integer value.
     fn record_i64(&mut self, field: &Field, value: i64) {
-        self.values
-            .insert(field.name(), serde_json::Value::from(value));
+        let (key, value) = self.mapper.map_field(field, JsonFieldValue::I64(value));
+        self.values.insert(key.into_owned(), value);
     }
 
     /// Visit an unsigned 64-bit integer value.
     fn record_u64(&mut self, field: &Field, value: u64) {
-        self.values
-            .insert(field.name(), serde_json::Value::from(value));
+        let (key, value) = self.mapper.map_field(field, JsonFieldValue::U64(value));
+        self.values.insert(key.into_owned(), value);
     }
 
     /// Visit a boolean value.
     fn record_bool(&mut self, field: &Field, value: bool) {
-        self.values
-            .insert(field.name(), serde_json::Value::from(value));
+        let (key, value) = self.mapper.map_field(field, JsonFieldValue::Bool(value));
+        self.values.insert(key.into_owned(), value);
     }
 
     /// Visit a string value.
     fn record_str(&mut self, field: &Field, value: &str) {
-        self.values
-            .insert(field.name(), serde_json::Value::from(value));
+        let (key, value) = self.mapper.map_field(field, JsonFieldValue::Str(value));
+        self.values.insert(key.into_owned(), value);
     }
 
     fn record_bytes(&mut self, field: &Field, value: &[u8]) {
-        self.values
-            .insert(field.name(), serde_json::Value::from(value));
+        let (key, value) = self.mapper.map_field(field, JsonFieldValue::Bytes(value));
+        self.values.insert(key.into_owned(), value);
     }
 
     fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
-        match field.name() {
-            // Skip fields that are actually log metadata that have already been handled
-            #[cfg(feature = "tracing-log")]
-            name if name.starts_with("log.") => (),
-            name if name.starts_with("r#") => {
-                self.values
-                    .insert(&name[2..], serde_json::Value::from(format!("{:?}", value)));
-            }
-            name => {
-                self.values
-                    .insert(name, serde_json::Value::from(format!("{:?}", value)));
-            }
-        };
+        // Skip fields that are actually log metadata that have already been handled.
+        #[cfg(feature = "tracing-log")]
+        if field.name().starts_with("log.") {
+            return;
+        }
+
+        let (key, value) = self.mapper.map_field(field, JsonFieldValue::Debug(value));
+        self.values.insert(key.into_owned(), value);
     }
 }
 #[cfg(test)]
@@ -587,6 +1251,50 @@ mod test {
         });
     }
 
+    #[test]
+    fn json_bytes_encoding_utf8_lossy() {
+        let expected =
+        "{\"timestamp\":\"fake time\",\"level\":\"INFO\",\"span\":{\"answer\":42,\"name\":\"json_span\",\"number\":3,\"slice\":\"abc\"},\"spans\":[{\"answer\":42,\"name\":\"json_span\",\"number\":3,\"slice\":\"abc\"}],\"target\":\"better_tracing::fmt::format::json::test\",\"fields\":{\"message\":\"some json test\"}}\n";
+        let subscriber = subscriber()
+            .flatten_event(false)
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_bytes_encoding(BytesEncoding::Utf8Lossy);
+        test_json(expected, subscriber, || {
+            let span = tracing::span!(
+                tracing::Level::INFO,
+                "json_span",
+                answer = 42,
+                number = 3,
+                slice = &b"abc"[..]
+            );
+            let _guard = span.enter();
+            tracing::info!("some json test");
+        });
+    }
+
+    #[test]
+    fn json_bytes_encoding_base64() {
+        let expected =
+        "{\"timestamp\":\"fake time\",\"level\":\"INFO\",\"span\":{\"answer\":42,\"name\":\"json_span\",\"number\":3,\"slice\":\"YWJj\"},\"spans\":[{\"answer\":42,\"name\":\"json_span\",\"number\":3,\"slice\":\"YWJj\"}],\"target\":\"better_tracing::fmt::format::json::test\",\"fields\":{\"message\":\"some json test\"}}\n";
+        let subscriber = subscriber()
+            .flatten_event(false)
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_bytes_encoding(BytesEncoding::Base64);
+        test_json(expected, subscriber, || {
+            let span = tracing::span!(
+                tracing::Level::INFO,
+                "json_span",
+                answer = 42,
+                number = 3,
+                slice = &b"abc"[..]
+            );
+            let _guard = span.enter();
+            tracing::info!("some json test");
+        });
+    }
+
     #[test]
     fn json_filename() {
         let current_path = Path::new("src")
@@ -646,6 +1354,38 @@ mod test {
         });
     }
 
+    #[test]
+    fn json_ecs_preset() {
+        let expected =
+        "{\"@timestamp\":\"fake time\",\"log.level\":\"info\",\"span\":{\"answer\":42,\"name\":\"json_span\",\"number\":3},\"spans\":[{\"answer\":42,\"name\":\"json_span\",\"number\":3}],\"log.logger\":\"better_tracing::fmt::format::json::test\",\"fields\":{\"message\":\"some json test\"}}\n";
+        let subscriber = subscriber()
+            .ecs()
+            .flatten_event(false)
+            .with_current_span(true)
+            .with_span_list(true);
+        test_json(expected, subscriber, || {
+            let span = tracing::span!(tracing::Level::INFO, "json_span", answer = 42, number = 3);
+            let _guard = span.enter();
+            tracing::info!("some json test");
+        });
+    }
+
+    #[test]
+    fn json_gcp_preset() {
+        let expected =
+        "{\"time\":\"fake time\",\"severity\":\"INFO\",\"span\":{\"answer\":42,\"name\":\"json_span\",\"number\":3},\"spans\":[{\"answer\":42,\"name\":\"json_span\",\"number\":3}],\"target\":\"better_tracing::fmt::format::json::test\",\"jsonPayload\":{\"message\":\"some json test\"}}\n";
+        let subscriber = subscriber()
+            .gcp()
+            .flatten_event(false)
+            .with_current_span(true)
+            .with_span_list(true);
+        test_json(expected, subscriber, || {
+            let span = tracing::span!(tracing::Level::INFO, "json_span", answer = 42, number = 3);
+            let _guard = span.enter();
+            tracing::info!("some json test");
+        });
+    }
+
     #[test]
     fn json_disabled_current_span_event() {
         let expected =
@@ -661,6 +1401,47 @@ mod test {
         });
     }
 
+    #[test]
+    fn json_flatten_span_fields() {
+        // `flatten_span_fields` writes each ancestor span's fields to the root as
+        // `span.<name>.<field>`, instead of nesting them under `span`/`spans`.
+        let expected =
+        "{\"timestamp\":\"fake time\",\"level\":\"INFO\",\"span.json_span.answer\":42,\"span.json_span.number\":3,\"target\":\"better_tracing::fmt::format::json::test\",\"fields\":{\"message\":\"some json test\"}}\n";
+        let subscriber = subscriber()
+            .flatten_event(false)
+            .with_current_span(false)
+            .with_span_list(false)
+            .flatten_span_fields("span.");
+        test_json(expected, subscriber, || {
+            let span = tracing::span!(tracing::Level::INFO, "json_span", answer = 42, number = 3);
+            let _guard = span.enter();
+            tracing::info!("some json test");
+        });
+    }
+
+    #[test]
+    fn json_flatten_span_fields_nested() {
+        let expected =
+        "{\"timestamp\":\"fake time\",\"level\":\"INFO\",\"span.json_span.answer\":42,\"span.json_span.number\":3,\"span.nested_json_span.answer\":43,\"span.nested_json_span.number\":4,\"target\":\"better_tracing::fmt::format::json::test\",\"fields\":{\"message\":\"some json test\"}}\n";
+        let subscriber = subscriber()
+            .flatten_event(false)
+            .with_current_span(false)
+            .with_span_list(false)
+            .flatten_span_fields("span.");
+        test_json(expected, subscriber, || {
+            let span = tracing::span!(tracing::Level::INFO, "json_span", answer = 42, number = 3);
+            let _guard = span.enter();
+            let span = tracing::span!(
+                tracing::Level::INFO,
+                "nested_json_span",
+                answer = 43,
+                number = 4
+            );
+            let _guard = span.enter();
+            tracing::info!("some json test");
+        });
+    }
+
     #[test]
     fn json_disabled_span_list_event() {
         let expected =
@@ -826,12 +1607,102 @@ mod test {
         });
     }
 
+    #[test]
+    fn json_pretty_round_trip() {
+        // Pretty-printed records span multiple lines and are delimited by a blank line
+        // rather than a single `\n`; make sure they're still indented and still parse back
+        // to the same value a compact record would.
+        let buffer = MockMakeWriter::default();
+        let subscriber = subscriber()
+            .json_pretty(true)
+            .flatten_event(false)
+            .with_writer(buffer.clone())
+            .with_timer(MockTime)
+            .finish();
+
+        with_default(subscriber, || {
+            tracing::info!(answer = 42, "some json test");
+        });
+
+        let buf = String::from_utf8(buffer.buf().to_vec()).unwrap();
+        assert!(
+            buf.trim_end().lines().count() > 1,
+            "pretty output should span multiple lines, got: {}",
+            buf
+        );
+        assert!(
+            buf.ends_with("\n\n"),
+            "pretty records should be delimited by a blank line, got: {}",
+            buf
+        );
+
+        let event = parse_as_json(&buffer);
+        assert_eq!(event["level"], "INFO");
+        assert_eq!(event["fields"]["message"], "some json test");
+        assert_eq!(event["fields"]["answer"], 42);
+    }
+
+    #[test]
+    fn json_static_fields_unflattened() {
+        let buffer = MockMakeWriter::default();
+        let subscriber = subscriber()
+            .with_static_fields([
+                ("service.name".to_string(), "my-service".into()),
+                ("version".to_string(), "1.2.3".into()),
+            ])
+            .flatten_event(false)
+            .with_writer(buffer.clone())
+            .with_timer(MockTime)
+            .finish();
+
+        with_default(subscriber, || {
+            tracing::info!("some json test");
+        });
+
+        let event = parse_as_json(&buffer);
+        assert_eq!(event["service.name"], "my-service");
+        assert_eq!(event["version"], "1.2.3");
+        assert_eq!(event["level"], "INFO");
+        assert_eq!(event["fields"]["message"], "some json test");
+    }
+
+    #[test]
+    fn json_static_fields_flattened() {
+        let buffer = MockMakeWriter::default();
+        let subscriber = subscriber()
+            .with_static_fields([("service.name".to_string(), "my-service".into())])
+            .flatten_event(true)
+            .with_writer(buffer.clone())
+            .with_timer(MockTime)
+            .finish();
+
+        with_default(subscriber, || {
+            tracing::info!(answer = 42, "some json test");
+        });
+
+        let event = parse_as_json(&buffer);
+        assert_eq!(event["service.name"], "my-service");
+        assert_eq!(event["level"], "INFO");
+        assert_eq!(event["message"], "some json test");
+        assert_eq!(event["answer"], 42);
+    }
+
     fn parse_as_json(buffer: &MockMakeWriter) -> serde_json::Value {
         let buf = String::from_utf8(buffer.buf().to_vec()).unwrap();
-        let json = buf
-            .lines()
-            .last()
-            .expect("expected at least one line to be written!");
+        // In `pretty` mode, a record spans multiple lines and is followed by a blank line
+        // rather than occupying exactly one line; fall back to splitting on that blank line
+        // whenever the buffer contains one, and only assume one-record-per-line otherwise.
+        let json = if buf.contains("\n\n") {
+            buf.split("\n\n")
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .last()
+                .expect("expected at least one record to be written!")
+        } else {
+            buf.lines()
+                .last()
+                .expect("expected at least one line to be written!")
+        };
         match serde_json::from_str(json) {
             Ok(v) => v,
             Err(e) => panic!(
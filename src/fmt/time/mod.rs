@@ -1,5 +1,6 @@
 //! Formatters for event timestamps.
 use crate::fmt::format::Writer;
+use std::cell::RefCell;
 use std::fmt;
 use std::time as stdtime;
 use std::time::Instant;
@@ -21,6 +22,13 @@ pub use time_crate::LocalTime;
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
 pub use time_crate::OffsetTime;
 
+#[cfg(feature = "time")]
+mod time_format;
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub use time_format::TimeFormat;
+
 /// [`chrono`]-based implementation for [`FormatTime`].
 #[cfg(feature = "chrono")]
 mod chrono_crate;
@@ -100,6 +108,53 @@ impl Clock for SystemClock {
     }
 }
 
+/// A [`Clock`] whose `now()` is set by the test, not the OS, so a
+/// `Timer<ManualClock, Rfc3339<…>>` (or any other `SystemTime`-based formatter) emits exact,
+/// reproducible timestamps instead of the real wall clock.
+///
+/// Cloning a `ManualClock` shares the same underlying time: every clone observes `set` and
+/// `advance` calls made through any other clone, which is what lets one be handed to a `Timer`
+/// while the test keeps another around to drive it.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: std::sync::Arc<std::sync::Mutex<stdtime::SystemTime>>,
+}
+
+impl ManualClock {
+    /// Creates a clock fixed at `start`.
+    pub fn new(start: stdtime::SystemTime) -> Self {
+        ManualClock {
+            now: std::sync::Arc::new(std::sync::Mutex::new(start)),
+        }
+    }
+
+    /// Sets the clock to `time`.
+    pub fn set(&self, time: stdtime::SystemTime) {
+        *self.now.lock().unwrap() = time;
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: stdtime::Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now += duration;
+    }
+}
+
+impl Default for ManualClock {
+    /// Starts the clock at the Unix epoch, so tests get a stable timestamp without having to
+    /// pick one.
+    fn default() -> Self {
+        ManualClock::new(stdtime::UNIX_EPOCH)
+    }
+}
+
+impl Clock for ManualClock {
+    type Snapshot = stdtime::SystemTime;
+    fn now(&self) -> Self::Snapshot {
+        *self.now.lock().unwrap()
+    }
+}
+
 /// RFC3339 formatter with configurable fractional digits and optional 'Z'.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Rfc3339<const DIGITS: u8, const Z: bool>;
@@ -113,6 +168,63 @@ impl<const D: u8, const Z: bool> TimestampFormatter<stdtime::SystemTime> for Rfc
     }
 }
 
+thread_local! {
+    // The whole-second Unix timestamp the cached prefix below was rendered for, and the
+    // rendered "seconds and coarser" prefix itself (the civil date/time up to, but not
+    // including, the fractional seconds and any trailing `Z`/offset).
+    static RFC3339_PREFIX_CACHE: RefCell<Option<(u64, String)>> = const { RefCell::new(None) };
+}
+
+/// Like [`Rfc3339`], but memoizes the rendered date/time-to-the-second prefix across calls
+/// that land within the same wall-clock second, so only the fractional digits (and the
+/// trailing `Z`) are recomputed on every call.
+///
+/// Formatting every event normally re-runs a full civil-date breakdown through
+/// [`datetime::DateTime`], even though thousands of consecutive events typically share the
+/// same whole second. This trades a small `thread_local!` cache for skipping that repeated
+/// date math on the (common) cache-hit path; output is byte-identical to [`Rfc3339`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CachedRfc3339<const DIGITS: u8, const Z: bool>;
+
+impl<const D: u8, const Z: bool> TimestampFormatter<stdtime::SystemTime> for CachedRfc3339<D, Z> {
+    fn format(&self, input: &stdtime::SystemTime, w: &mut Writer<'_>) -> fmt::Result {
+        let (whole_secs, subsec_nanos) = match input.duration_since(stdtime::UNIX_EPOCH) {
+            Ok(d) => (d.as_secs(), d.subsec_nanos()),
+            // Pre-epoch timestamps are rare enough, and this cache is purely an
+            // optimization, that it's simplest to just fall back to the uncached path.
+            Err(_) => return Rfc3339::<D, Z>.format(input, w),
+        };
+
+        RFC3339_PREFIX_CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            // A whole-second value that doesn't match the cached one also covers the clock
+            // going backwards between calls: the comparison simply misses and we recompute.
+            let is_fresh = matches!(&*cache, Some((secs, _)) if *secs == whole_secs);
+            if !is_fresh {
+                let mut prefix = String::new();
+                datetime::DateTime::from(*input).fmt_rfc3339_with_subsec_to(
+                    &mut Writer::new(&mut prefix),
+                    0,
+                    false,
+                )?;
+                *cache = Some((whole_secs, prefix));
+            }
+
+            w.write_str(&cache.as_ref().expect("just populated above").1)?;
+
+            let digits = if D > 9 { 9 } else { D };
+            if digits > 0 {
+                let scale = 10u32.pow(9 - digits as u32);
+                write!(w, ".{:0width$}", subsec_nanos / scale, width = digits as usize)?;
+            }
+            if Z {
+                w.write_str("Z")?;
+            }
+            Ok(())
+        })
+    }
+}
+
 /// Returns a new `SystemTime` timestamp provider.
 ///
 /// This can then be configured further to determine how timestamps should be
@@ -207,6 +319,192 @@ impl FormatTime for Uptime {
     }
 }
 
+/// A `Clock` that returns the elapsed time since it was constructed, as a `Duration`.
+///
+/// This is the `Clock` half of [`Timer<MonotonicClock, Humanized<N>>`](Timer), the humanized
+/// counterpart to [`Uptime`]'s fixed `{secs}.{nanos}s` form.
+#[derive(Debug, Clone, Copy)]
+pub struct MonotonicClock {
+    epoch: Instant,
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        MonotonicClock {
+            epoch: Instant::now(),
+        }
+    }
+}
+
+impl From<Instant> for MonotonicClock {
+    fn from(epoch: Instant) -> Self {
+        MonotonicClock { epoch }
+    }
+}
+
+impl Clock for MonotonicClock {
+    type Snapshot = stdtime::Duration;
+    fn now(&self) -> Self::Snapshot {
+        self.epoch.elapsed()
+    }
+}
+
+/// Renders a `Duration` snapshot in compound human-readable units, e.g. `1h 3m 5s`,
+/// `450ms`, or `12µs`, picking the largest non-zero unit(s) down to at most `MAX_COMPONENTS`
+/// components.
+///
+/// Whole seconds and coarser are broken into `d`/`h`/`m`/`s` components; durations under a
+/// second fall through to `ms`/`µs`/`ns` instead, since seconds and sub-second units are
+/// never mixed in the same rendering. A zero duration prints as `0s`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Humanized<const MAX_COMPONENTS: u8>;
+
+impl<const MAX_COMPONENTS: u8> TimestampFormatter<stdtime::Duration> for Humanized<MAX_COMPONENTS> {
+    fn format(&self, input: &stdtime::Duration, w: &mut Writer<'_>) -> fmt::Result {
+        if input.is_zero() {
+            return w.write_str("0s");
+        }
+
+        let secs = input.as_secs();
+        let components: [(u64, &str); 4] = if secs > 0 {
+            [
+                (secs / 86_400, "d"),
+                ((secs % 86_400) / 3_600, "h"),
+                ((secs % 3_600) / 60, "m"),
+                (secs % 60, "s"),
+            ]
+        } else {
+            let nanos = input.subsec_nanos();
+            [
+                (u64::from(nanos / 1_000_000), "ms"),
+                (u64::from((nanos % 1_000_000) / 1_000), "µs"),
+                (u64::from(nanos % 1_000), "ns"),
+                (0, ""),
+            ]
+        };
+
+        let max_components = MAX_COMPONENTS.max(1) as usize;
+        let mut written = 0usize;
+        for (value, suffix) in components {
+            if value == 0 || suffix.is_empty() || written >= max_components {
+                continue;
+            }
+            if written > 0 {
+                w.write_str(" ")?;
+            }
+            write!(w, "{value}{suffix}")?;
+            written += 1;
+        }
+        Ok(())
+    }
+}
+
+impl Uptime {
+    /// Returns a `Timer` that renders the elapsed uptime in compound human-readable units
+    /// (e.g. `1h 3m 5s`), instead of `Uptime`'s own fixed `{secs}.{nanos}s` form.
+    pub fn humanized() -> Timer<MonotonicClock, Humanized<3>> {
+        Timer(MonotonicClock::default(), Humanized)
+    }
+}
+
+/// Renders wall-clock time in a terse, human-friendly absolute form: `YYYY-MM-DD HH:MM:SS`,
+/// with no fractional seconds and no `Z`/offset suffix — pairs well with [`Humanized`]
+/// relative uptimes in output meant for a human watching logs live, rather than a machine
+/// parsing them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HumanizedAbsolute;
+
+impl TimestampFormatter<stdtime::SystemTime> for HumanizedAbsolute {
+    fn format(&self, input: &stdtime::SystemTime, w: &mut Writer<'_>) -> fmt::Result {
+        let mut buf = String::new();
+        Rfc3339::<0, false>.format(input, &mut Writer::new(&mut buf))?;
+        w.write_str(&buf.replacen('T', " ", 1))
+    }
+}
+
+impl SystemTime {
+    /// Wall-clock time in a terse absolute form (`YYYY-MM-DD HH:MM:SS`); see
+    /// [`HumanizedAbsolute`].
+    pub const fn humanized() -> Timer<SystemClock, HumanizedAbsolute> {
+        Timer(SystemClock, HumanizedAbsolute)
+    }
+}
+
+/// RFC3339 timestamps adjusted to a fixed UTC offset, with a real `+HH:MM`/`-HH:MM` suffix
+/// instead of `Z`.
+///
+/// Unlike the `local-time` feature's `OffsetTime` (which the crate itself documents as
+/// `unsound_local_offset`, since it reads the process-global timezone at format time — a
+/// data race in a multi-threaded program), the offset here is a plain number resolved once,
+/// up front, either supplied directly or read from the environment a single time at
+/// construction. It's applied to the `SystemTime` snapshot before the existing no-deps
+/// [`datetime::DateTime`] civil breakdown, so it needs no `time` crate dependency.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedOffset<const DIGITS: u8> {
+    offset_secs: i32,
+}
+
+impl<const D: u8> FixedOffset<D> {
+    /// Builds a formatter for the given UTC offset, in whole seconds (e.g. `-18_000` for US
+    /// Eastern Standard Time).
+    pub const fn from_offset_seconds(offset_secs: i32) -> Self {
+        FixedOffset { offset_secs }
+    }
+
+    /// Resolves the offset once from the `BETTER_TRACING_UTC_OFFSET_SECONDS` environment
+    /// variable (whole seconds), defaulting to UTC (`0`) if it's unset or unparsable.
+    ///
+    /// Call this once, at startup: like any other env var read, it's racy if something else
+    /// in the process calls `set_var` concurrently, but that hazard is about mutating the
+    /// environment at runtime, not about how the offset itself is obtained or applied.
+    pub fn from_env() -> Self {
+        let offset_secs = std::env::var("BETTER_TRACING_UTC_OFFSET_SECONDS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+        Self::from_offset_seconds(offset_secs)
+    }
+}
+
+impl<const D: u8> TimestampFormatter<stdtime::SystemTime> for FixedOffset<D> {
+    fn format(&self, input: &stdtime::SystemTime, w: &mut Writer<'_>) -> fmt::Result {
+        let offset = stdtime::Duration::from_secs(self.offset_secs.unsigned_abs() as u64);
+        let adjusted = if self.offset_secs >= 0 {
+            input.checked_add(offset)
+        } else {
+            input.checked_sub(offset)
+        }
+        .unwrap_or(*input);
+
+        let dt = datetime::DateTime::from(adjusted);
+        let digits = if D > 9 { 9 } else { D };
+        dt.fmt_rfc3339_with_subsec_to(w, digits, false)?;
+
+        let sign = if self.offset_secs < 0 { '-' } else { '+' };
+        let abs_secs = self.offset_secs.unsigned_abs();
+        write!(w, "{sign}{:02}:{:02}", abs_secs / 3600, (abs_secs % 3600) / 60)
+    }
+}
+
+impl SystemTime {
+    /// RFC3339 with no fractional seconds, at a fixed UTC offset; see [`FixedOffset`].
+    pub const fn rfc3339_seconds_at_offset(offset_secs: i32) -> Timer<SystemClock, FixedOffset<0>> {
+        Timer(SystemClock, FixedOffset::from_offset_seconds(offset_secs))
+    }
+
+    /// RFC3339 with 3 fractional digits (milliseconds), at a fixed UTC offset; see
+    /// [`FixedOffset`].
+    pub const fn rfc3339_millis_at_offset(offset_secs: i32) -> Timer<SystemClock, FixedOffset<3>> {
+        Timer(SystemClock, FixedOffset::from_offset_seconds(offset_secs))
+    }
+
+    /// RFC3339 with 9 fractional digits (nanoseconds), at a fixed UTC offset; see
+    /// [`FixedOffset`].
+    pub const fn rfc3339_nanos_at_offset(offset_secs: i32) -> Timer<SystemClock, FixedOffset<9>> {
+        Timer(SystemClock, FixedOffset::from_offset_seconds(offset_secs))
+    }
+}
+
 // --- Built-in, no-deps formatters and ergonomic constructors ------------------
 
 /// Seconds since UNIX epoch (UTC), using floor semantics for pre-epoch values.
@@ -294,6 +592,27 @@ impl SystemTime {
         Timer(SystemClock, Rfc3339)
     }
 
+    /// RFC3339 with no fractional seconds and 'Z', like [`rfc3339_seconds`](Self::rfc3339_seconds)
+    /// but caching the rendered prefix across calls within the same whole second; see
+    /// [`CachedRfc3339`].
+    pub const fn rfc3339_seconds_cached() -> Timer<SystemClock, CachedRfc3339<0, true>> {
+        Timer(SystemClock, CachedRfc3339)
+    }
+
+    /// RFC3339 with 3 fractional digits (milliseconds) and 'Z', like
+    /// [`rfc3339_millis`](Self::rfc3339_millis) but caching the rendered prefix across calls
+    /// within the same whole second; see [`CachedRfc3339`].
+    pub const fn rfc3339_millis_cached() -> Timer<SystemClock, CachedRfc3339<3, true>> {
+        Timer(SystemClock, CachedRfc3339)
+    }
+
+    /// RFC3339 with 9 fractional digits (nanoseconds) and 'Z', like
+    /// [`rfc3339_nanos`](Self::rfc3339_nanos) but caching the rendered prefix across calls
+    /// within the same whole second; see [`CachedRfc3339`].
+    pub const fn rfc3339_nanos_cached() -> Timer<SystemClock, CachedRfc3339<9, true>> {
+        Timer(SystemClock, CachedRfc3339)
+    }
+
     /// Seconds since UNIX epoch (UTC).
     pub const fn unix_seconds() -> Timer<SystemClock, UnixSeconds> {
         Timer(SystemClock, UnixSeconds)
@@ -345,3 +664,23 @@ impl SystemTime {
         Timer(SystemClock, TimeOfDay)
     }
 }
+
+#[cfg(feature = "time")]
+impl SystemTime {
+    /// RFC3339, rendered through the `time` crate's well-known format rather than the no-deps
+    /// [`Rfc3339`] formatter; see [`time_format::TimeFormat`].
+    pub const fn rfc3339_time_crate() -> Timer<SystemClock, time_format::TimeFormat<time::format_description::well_known::Rfc3339>> {
+        Timer(SystemClock, time_format::TimeFormat::rfc3339())
+    }
+
+    /// Renders through an arbitrary `time`-crate format description; see
+    /// [`time_format::TimeFormat::new`].
+    pub fn with_time_format(
+        format: &str,
+    ) -> Result<
+        Timer<SystemClock, time_format::TimeFormat<time::format_description::OwnedFormatItem>>,
+        time::error::InvalidFormatDescription,
+    > {
+        Ok(Timer(SystemClock, time_format::TimeFormat::new(format)?))
+    }
+}
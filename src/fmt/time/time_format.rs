@@ -0,0 +1,65 @@
+//! A [`TimestampFormatter`] that renders through a `time`-crate format description, so the
+//! `Clock`/`Timer` combinator machinery can drive arbitrary `time` formats instead of just the
+//! built-in [`super::Rfc3339`]/[`super::CachedRfc3339`] renderers.
+use super::{TimestampFormatter, Writer};
+use crate::fmt::writer::WriteAdaptor;
+use std::fmt;
+use std::time::SystemTime;
+use time::format_description::well_known::Rfc3339;
+use time::format_description::OwnedFormatItem;
+use time::formatting::Formattable;
+use time::OffsetDateTime;
+
+/// Formats a [`SystemTime`] snapshot through any `time`-crate [`Formattable`] description,
+/// whether a [`well_known`](time::format_description::well_known) format or one parsed at
+/// runtime via [`TimeFormat::new`].
+///
+/// Unlike the separate `UtcTime`/`OffsetTime` `FormatTime` impls, this is a
+/// [`TimestampFormatter`], so it composes with [`super::Timer`] like every other formatter in
+/// this module. The timestamp is always rendered in UTC.
+#[derive(Debug, Clone)]
+pub struct TimeFormat<F> {
+    format: F,
+}
+
+impl TimeFormat<OwnedFormatItem> {
+    /// Parses a `time`-crate format description string (see the [`time` book] for the syntax).
+    ///
+    /// [`time` book]: https://time-rs.github.io/book/api/format-description.html
+    pub fn new(format: &str) -> Result<Self, time::error::InvalidFormatDescription> {
+        let format = time::format_description::parse_owned::<2>(format)?;
+        Ok(TimeFormat { format })
+    }
+}
+
+impl<F> TimeFormat<F>
+where
+    F: Formattable,
+{
+    /// Wraps a pre-built format description, such as
+    /// [`Rfc3339`](time::format_description::well_known::Rfc3339) or
+    /// [`Iso8601`](time::format_description::well_known::Iso8601).
+    pub const fn with_format(format: F) -> Self {
+        TimeFormat { format }
+    }
+}
+
+impl TimeFormat<Rfc3339> {
+    /// RFC3339, via the `time` crate's well-known format rather than [`super::Rfc3339`]'s
+    /// no-deps implementation.
+    pub const fn rfc3339() -> Self {
+        TimeFormat::with_format(Rfc3339)
+    }
+}
+
+impl<F> TimestampFormatter<SystemTime> for TimeFormat<F>
+where
+    F: Formattable,
+{
+    fn format(&self, input: &SystemTime, w: &mut Writer<'_>) -> fmt::Result {
+        let dt = OffsetDateTime::from(*input);
+        dt.format_into(&mut WriteAdaptor::new(w), &self.format)
+            .map(|_| ())
+            .map_err(|_| fmt::Error)
+    }
+}
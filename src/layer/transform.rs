@@ -1,20 +1,38 @@
 //! Field transformation layer for zero-allocation span field manipulation.
 //!
 //! This layer intercepts field recording and applies transformations based on
-//! configurable rules, storing the transformed results in the existing
-//! `FormattedFields<N>` extension storage for zero-cost access during formatting.
+//! configurable rules. [`FieldTransformLayer`] on its own only rewrites the `Debug`/`Display`
+//! text it stores for a span's own fields; to have hidden, renamed, truncated, prefixed,
+//! transformed, or redacted fields actually show up in a formatter's output — text or JSON,
+//! for spans or events — configure [`TransformFormatFields`] as that formatter's
+//! `.fmt_fields(...)`, sharing the same rule set via a [`Handle`].
+//!
+//! Rules can be built programmatically with [`FieldTransformLayer::with_target_transform`],
+//! gated by an arbitrary predicate over the callsite's [`Metadata`] with
+//! [`FieldTransformLayer::with_filter_transform`] rather than (or in addition to) a target
+//! pattern, or parsed from a compact directive string with [`FieldTransformLayer::from_str`] /
+//! [`FieldTransformLayer::from_env`], the same way an `EnvFilter` is driven by `RUST_LOG`.
 
 use crate::{
-    field::Visit,
-    fmt::{format::Writer, FormattedFields},
+    field::{RecordFields, Visit},
+    fmt::{
+        format::{FormatFields, Writer},
+        FormattedFields,
+    },
     layer::{Context, Layer},
     registry::LookupSpan,
 };
-use std::{fmt, marker::PhantomData};
+use std::{
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    sync::{Arc, Mutex, OnceLock, RwLock},
+};
 use tracing_core::{
-    field::Field,
+    callsite::Identifier,
+    field::{Field, FieldSet},
     span::{Attributes, Id, Record},
-    Subscriber,
+    Level, Metadata, Subscriber,
 };
 
 /// A layer that transforms span fields during recording.
@@ -52,31 +70,506 @@ pub struct TransformConfig {
     target_rules: Vec<TargetRule>,
 }
 
+impl TransformConfig {
+    /// An empty configuration with no rules.
+    pub fn new() -> Self {
+        Self {
+            target_rules: Vec::new(),
+        }
+    }
+
+    /// Add a rule for `target_pattern`, built the same way as
+    /// [`FieldTransformLayer::with_target_transform`].
+    pub fn add_target_transform<F>(&mut self, target_pattern: impl Into<String>, builder: F) -> &mut Self
+    where
+        F: FnOnce(TargetRuleBuilder) -> TargetRuleBuilder,
+    {
+        self.target_rules
+            .push(builder(TargetRuleBuilder::new(target_pattern)).build());
+        self
+    }
+
+    /// Add a rule gated by a custom predicate, built the same way as
+    /// [`FieldTransformLayer::with_filter_transform`].
+    pub fn add_filter_transform<F, B>(&mut self, filter: F, builder: B) -> &mut Self
+    where
+        F: Fn(&Metadata<'_>) -> bool + Send + Sync + 'static,
+        B: FnOnce(TargetRuleBuilder) -> TargetRuleBuilder,
+    {
+        self.target_rules
+            .push(builder(TargetRuleBuilder::new("").filter(filter)).build());
+        self
+    }
+
+    /// Remove every rule for the given target pattern.
+    pub fn remove_target(&mut self, target_pattern: &str) -> &mut Self {
+        self.target_rules
+            .retain(|rule| rule.target_pattern != target_pattern);
+        self
+    }
+}
+
+impl Default for TransformConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Transformation rules for a specific target pattern.
-#[derive(Debug)]
+#[derive(Clone)]
 pub struct TargetRule {
-    target_pattern: &'static str,
-    field_renames: Vec<(&'static str, &'static str)>,
-    hidden_fields: Vec<&'static str>,
+    target_pattern: String,
+    /// When set, this rule only applies to spans/events that declare a field with this
+    /// name, parsed from a `target[field]` pattern (see [`TargetRuleBuilder::new`]).
+    field_scope: Option<String>,
+    /// When set, this rule only applies to spans/events at this level or more verbose (a
+    /// `=debug` rule covers `DEBUG` and `TRACE`), parsed from a `target=level` pattern suffix,
+    /// mirroring `EnvFilter` directive syntax.
+    level_threshold: Option<Level>,
+    /// When set, this rule only applies to spans/events this predicate accepts, set via
+    /// [`TargetRuleBuilder::filter`]/[`FieldTransformLayer::with_filter_transform`].
+    custom_filter: Option<Arc<dyn Fn(&Metadata<'_>) -> bool + Send + Sync>>,
+    field_renames: Vec<(String, String)>,
+    hidden_fields: Vec<String>,
     field_transforms: Vec<FieldTransform>,
+    /// Cross-field redaction rules added via [`TargetRuleBuilder::redact_value_matching`],
+    /// applied to every field's rendered value regardless of its name.
+    value_redactions: Vec<ValueRedaction>,
+}
+
+impl fmt::Debug for TargetRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TargetRule")
+            .field("target_pattern", &self.target_pattern)
+            .field("field_scope", &self.field_scope)
+            .field("level_threshold", &self.level_threshold)
+            .field("custom_filter", &self.custom_filter.as_ref().map(|_| ".."))
+            .field("field_renames", &self.field_renames)
+            .field("hidden_fields", &self.hidden_fields)
+            .field("field_transforms", &self.field_transforms)
+            .field("value_redactions", &self.value_redactions)
+            .finish()
+    }
 }
 
 /// A field transformation.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FieldTransform {
-    field_name: &'static str,
+    field_name: String,
     transform_type: TransformType,
 }
 
 /// Types of field transformations.
-#[derive(Debug)]
+#[derive(Clone)]
 pub enum TransformType {
     /// Truncate to N characters
     Truncate(usize),
     /// Add a static prefix
-    Prefix(&'static str),
+    Prefix(String),
     /// Apply a custom transformation function
-    Custom(fn(&str) -> String),
+    Custom(Arc<dyn Fn(&str) -> String + Send + Sync>),
+    /// Apply a transformation only when the recorded value matches a [`Match`] predicate.
+    Conditional(Match, Arc<dyn Fn(&str) -> String + Send + Sync>),
+    /// Mask the portion of a value matched by a [`RedactMatcher`], keeping the last
+    /// `keep_last` characters of each match visible.
+    Redact { matcher: RedactMatcher, keep_last: usize },
+    /// Unconditionally rewrite every value of this field according to a [`RedactMode`], set
+    /// via [`TargetRuleBuilder::redact_field_with_mode`].
+    RedactMode(RedactMode),
+    /// Apply a type-preserving transformation that sees (and returns) the original
+    /// recorded value's kind, rather than its formatted text. See [`TransformValue`].
+    Typed(Arc<dyn Fn(TransformValue) -> TransformValue + Send + Sync>),
+    /// Apply a transformation that sees the rendered values of every *other* field recorded
+    /// on the same event, so it can make a decision that depends on more than its own field.
+    /// See [`TargetRuleBuilder::transform_with_context`] and [`FieldContext`].
+    WithContext(Arc<dyn Fn(&str, &FieldContext<'_>) -> String + Send + Sync>),
+}
+
+impl fmt::Debug for TransformType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransformType::Truncate(n) => f.debug_tuple("Truncate").field(n).finish(),
+            TransformType::Prefix(p) => f.debug_tuple("Prefix").field(p).finish(),
+            TransformType::Custom(_) => f.write_str("Custom(..)"),
+            TransformType::Conditional(m, _) => f.debug_tuple("Conditional").field(m).finish(),
+            TransformType::Redact { matcher, keep_last } => f
+                .debug_struct("Redact")
+                .field("matcher", matcher)
+                .field("keep_last", keep_last)
+                .finish(),
+            TransformType::RedactMode(mode) => f.debug_tuple("RedactMode").field(mode).finish(),
+            TransformType::Typed(_) => f.write_str("Typed(..)"),
+            TransformType::WithContext(_) => f.write_str("WithContext(..)"),
+        }
+    }
+}
+
+/// A strongly-typed field value, mirroring the primitive kinds `tracing`'s [`Visit`] trait
+/// records (`record_i64`/`record_u64`/`record_f64`/`record_bool`/`record_str`/`record_debug`).
+///
+/// Passed to a closure registered via [`TargetRuleBuilder::transform_value`] so it can
+/// branch on — and return — the original value's actual type instead of parsing a
+/// formatted string, and preserve that type all the way through to formatters like the
+/// JSON one that render numbers and booleans as JSON-native values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransformValue {
+    /// A value recorded with `record_i64`.
+    I64(i64),
+    /// A value recorded with `record_u64`.
+    U64(u64),
+    /// A value recorded with `record_f64`.
+    F64(f64),
+    /// A value recorded with `record_bool`.
+    Bool(bool),
+    /// A value recorded with `record_str`.
+    Str(String),
+    /// A value recorded with `record_debug`, already rendered via `{:?}`.
+    Debug(String),
+}
+
+impl TransformValue {
+    /// Renders this value as text, for contexts (like the text span-field writer) that
+    /// only ever produce a formatted string regardless of the original type.
+    fn render(&self) -> String {
+        match self {
+            TransformValue::I64(v) => v.to_string(),
+            TransformValue::U64(v) => v.to_string(),
+            TransformValue::F64(v) => v.to_string(),
+            TransformValue::Bool(v) => v.to_string(),
+            TransformValue::Str(v) => v.clone(),
+            TransformValue::Debug(v) => v.clone(),
+        }
+    }
+
+    /// Forwards this value to `visitor` under its own kind, so a type-preserving
+    /// transform's result reaches the inner formatter as a real `i64`/`f64`/`bool`/etc.
+    /// rather than a string.
+    fn record(self, visitor: &mut dyn Visit, field: &Field) {
+        match self {
+            TransformValue::I64(v) => visitor.record_i64(field, v),
+            TransformValue::U64(v) => visitor.record_u64(field, v),
+            TransformValue::F64(v) => visitor.record_f64(field, v),
+            TransformValue::Bool(v) => visitor.record_bool(field, v),
+            TransformValue::Str(v) => visitor.record_str(field, &v),
+            TransformValue::Debug(v) => visitor.record_str(field, &v),
+        }
+    }
+}
+
+/// A read-only, point-in-time view of an event's other recorded fields, passed to a
+/// [`TargetRuleBuilder::transform_with_context`] closure.
+///
+/// Values reflect the same event's other fields *after* any simple (non-context) per-field
+/// transform has already run on them — e.g. if `uid` is truncated and `phase` is recolored by
+/// context, the context sees `uid`'s truncated form, not its raw recorded value — but *before*
+/// any other context transform has run, so two context transforms can't observe each other's
+/// output. Field order matches the order `tracing` originally visited them in.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldContext<'a> {
+    fields: &'a [(String, String)],
+    exclude: &'a str,
+}
+
+impl<'a> FieldContext<'a> {
+    fn new(fields: &'a [(String, String)], exclude: &'a str) -> Self {
+        Self { fields, exclude }
+    }
+
+    /// Looks up another field's rendered value by name. Returns `None` both when the field
+    /// was never recorded and when `name` is the field the running transform is computing.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name == name && field_name != self.exclude)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Iterates over every other recorded field as `(name, rendered value)` pairs, in visit
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .filter(|(name, _)| name != self.exclude)
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+/// A matcher used by [`TargetRuleBuilder::redact_field`]/[`TargetRuleBuilder::redact_matching`]
+/// to decide which rendered values (or, for [`RedactMatcher::Regex`], which substrings of a
+/// value) get masked.
+#[derive(Debug, Clone)]
+pub enum RedactMatcher {
+    /// Matches a value equal to this string.
+    Exact(String),
+    /// Matches a value starting with this prefix.
+    Prefix(String),
+    /// Matches a value containing this substring.
+    Contains(String),
+    /// Matches every substring of a value satisfying this regex, masking each independently.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl RedactMatcher {
+    /// Redact `rendered` according to this matcher, keeping the last `keep_last` characters
+    /// of each match unmasked. Returns `None` when nothing matched, so the caller can forward
+    /// the original value untouched.
+    fn redact(&self, rendered: &str, keep_last: usize) -> Option<String> {
+        match self {
+            RedactMatcher::Exact(s) => (rendered == s).then(|| mask(rendered, keep_last)),
+            RedactMatcher::Prefix(p) => rendered
+                .starts_with(p.as_str())
+                .then(|| mask(rendered, keep_last)),
+            RedactMatcher::Contains(c) => rendered
+                .contains(c.as_str())
+                .then(|| mask(rendered, keep_last)),
+            #[cfg(feature = "regex")]
+            RedactMatcher::Regex(re) => {
+                if re.is_match(rendered) {
+                    Some(
+                        re.replace_all(rendered, |caps: &regex::Captures<'_>| {
+                            mask(&caps[0], keep_last)
+                        })
+                        .into_owned(),
+                    )
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Redact `debug_rendered`, the output of `{:?}` on a recorded value, applying the
+    /// matcher to the content inside the surrounding quotes (if any) rather than the quotes
+    /// themselves, and re-adding them afterward.
+    fn redact_debug(&self, debug_rendered: &str, keep_last: usize) -> Option<String> {
+        match debug_rendered
+            .strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+        {
+            Some(inner) => self
+                .redact(inner, keep_last)
+                .map(|masked| format!("\"{}\"", masked)),
+            None => self.redact(debug_rendered, keep_last),
+        }
+    }
+}
+
+/// How a matched secret value should be rewritten, used by
+/// [`TargetRuleBuilder::redact_field_with_mode`] and
+/// [`TargetRuleBuilder::redact_value_matching`].
+///
+/// Unlike the plain masking [`redact_field`](TargetRuleBuilder::redact_field) always applies,
+/// a mode lets a rule pick between fully masking a value, replacing it with a stable
+/// correlation hash, or dropping it outright.
+#[derive(Debug, Clone)]
+pub enum RedactMode {
+    /// Replace all but the last `keep_last` characters with `*`, the same masking
+    /// [`redact_field`](TargetRuleBuilder::redact_field) does.
+    Mask {
+        /// Number of trailing characters left visible.
+        keep_last: usize,
+    },
+    /// Replace the value with the first 8 hex characters of a stable, non-cryptographic
+    /// 64-bit hash, so two occurrences of the same secret are still recognizable as equal
+    /// across log lines without revealing either one.
+    Hash,
+    /// Omit the field entirely, like [`hide_field`](TargetRuleBuilder::hide_field), but
+    /// recorded as a distinct transform kind so audit tooling can tell a deliberately
+    /// redacted secret apart from a field hidden just to cut noise.
+    Drop,
+}
+
+impl RedactMode {
+    /// Applies this mode to `rendered`, returning `None` for [`RedactMode::Drop`] so the
+    /// caller omits the field entirely, like a hidden one.
+    fn apply(&self, rendered: &str) -> Option<String> {
+        match self {
+            RedactMode::Mask { keep_last } => Some(mask(rendered, *keep_last)),
+            RedactMode::Hash => Some(format!("{:08x}", (fnv1a64(rendered) >> 32) as u32)),
+            RedactMode::Drop => None,
+        }
+    }
+}
+
+/// A stable, non-cryptographic 64-bit FNV-1a hash, used by [`RedactMode::Hash`] so repeated
+/// occurrences of the same secret correlate across log lines without this crate depending on
+/// a hashing crate just for that.
+fn fnv1a64(s: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    s.bytes()
+        .fold(OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
+/// A cross-field redaction rule added via [`TargetRuleBuilder::redact_value_matching`]: every
+/// field's rendered value is tested against `predicate` regardless of the field's name, so a
+/// secret like `Authorization: Bearer ...` is caught no matter which key carries it.
+#[derive(Clone)]
+struct ValueRedaction {
+    predicate: Arc<dyn Fn(&str) -> bool + Send + Sync>,
+    mode: RedactMode,
+}
+
+impl fmt::Debug for ValueRedaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValueRedaction")
+            .field("predicate", &"..")
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl ValueRedaction {
+    /// Returns the redacted form of `rendered` when `predicate` matches it: `Some(None)` for
+    /// a [`RedactMode::Drop`] match (omit the field), `Some(Some(value))` for a masked/hashed
+    /// match, or `None` when the predicate didn't match at all.
+    fn apply(&self, rendered: &str) -> Option<Option<String>> {
+        (self.predicate)(rendered).then(|| self.mode.apply(rendered))
+    }
+}
+
+/// Applies the first matching rule in `value_redactions` to `rendered`, if any.
+///
+/// Returns `Some(None)` when a [`RedactMode::Drop`] rule matched (the field should be
+/// omitted), `Some(Some(value))` when a masking/hashing rule matched, or `None` when no rule
+/// matched and `rendered` should be forwarded untouched (possibly to a per-field transform
+/// such as `truncate_field`, which then sees the original value).
+fn apply_value_redactions(rule: &TargetRule, rendered: &str) -> Option<Option<String>> {
+    rule.value_redactions
+        .iter()
+        .find_map(|redaction| redaction.apply(rendered))
+}
+
+/// Like [`apply_value_redactions`], but when `is_debug` is set, matches `predicate`s against
+/// the content inside `rendered`'s surrounding quotes (if any) rather than the quotes
+/// themselves, and re-adds them to a masked/hashed result afterward — mirroring
+/// [`RedactMatcher::redact_debug`].
+fn apply_value_redactions_aware(rule: &TargetRule, rendered: &str, is_debug: bool) -> Option<Option<String>> {
+    if !is_debug {
+        return apply_value_redactions(rule, rendered);
+    }
+    match rendered.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(inner) => apply_value_redactions(rule, inner)
+            .map(|redacted| redacted.map(|value| format!("\"{}\"", value))),
+        None => apply_value_redactions(rule, rendered),
+    }
+}
+
+/// Replaces all but the last `keep_last` characters of `s` with `*`, splitting on `char`
+/// boundaries so multi-byte characters are never torn in half.
+fn mask(s: &str, keep_last: usize) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    if keep_last >= chars.len() {
+        return s.to_string();
+    }
+    let hidden = chars.len() - keep_last;
+    chars
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| if i < hidden { '*' } else { c })
+        .collect()
+}
+
+/// Truncates `value` to at most `max_len` characters, splitting on `char` boundaries so
+/// multi-byte characters are never torn in half, and appends `...` when truncation occurred.
+fn truncate_chars(value: &str, max_len: usize) -> String {
+    if value.chars().count() > max_len {
+        let mut truncated: String = value.chars().take(max_len).collect();
+        truncated.push_str("...");
+        truncated
+    } else {
+        value.to_string()
+    }
+}
+
+/// A value predicate used by [`TargetRuleBuilder::transform_field_if`] to decide whether a
+/// recorded field's value should be rewritten.
+///
+/// Typed literals (`Match::Bool`/`I64`/`U64`/`F64`) compare the recorded value numerically
+/// when the visitor records that exact type, and otherwise fall back to parsing the
+/// formatted value. A [`Match::Regex`] is always applied to the value's string or `Debug`
+/// rendering.
+#[derive(Debug, Clone)]
+pub enum Match {
+    /// Matches a recorded `bool` equal to the given value.
+    Bool(bool),
+    /// Matches a recorded `i64` equal to the given value.
+    I64(i64),
+    /// Matches a recorded `u64` equal to the given value.
+    U64(u64),
+    /// Matches a recorded `f64` equal to the given value.
+    F64(f64),
+    /// Matches when the value's string/`Debug` rendering matches a compiled regex.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Match {
+    /// Build a [`Match`] from a regular expression, compiling it eagerly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid regular expression. Use
+    /// [`Match::try_regex`] to handle invalid patterns gracefully.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Self {
+        Self::try_regex(pattern).expect("invalid regex pattern")
+    }
+
+    /// Build a [`Match`] from a regular expression, returning an error if it fails to
+    /// compile.
+    #[cfg(feature = "regex")]
+    pub fn try_regex(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Match::Regex(regex::Regex::new(pattern)?))
+    }
+
+    fn matches_rendered(&self, rendered: &str) -> bool {
+        match self {
+            Match::Bool(b) => rendered.parse::<bool>().map(|v| v == *b).unwrap_or(false),
+            Match::I64(n) => rendered.parse::<i64>().map(|v| v == *n).unwrap_or(false),
+            Match::U64(n) => rendered.parse::<u64>().map(|v| v == *n).unwrap_or(false),
+            Match::F64(n) => rendered.parse::<f64>().map(|v| v == *n).unwrap_or(false),
+            #[cfg(feature = "regex")]
+            Match::Regex(re) => re.is_match(rendered),
+        }
+    }
+
+    fn matches_bool(&self, value: bool) -> bool {
+        match self {
+            Match::Bool(b) => *b == value,
+            #[cfg(feature = "regex")]
+            Match::Regex(_) => self.matches_rendered(&value.to_string()),
+            _ => false,
+        }
+    }
+
+    fn matches_i64(&self, value: i64) -> bool {
+        match self {
+            Match::I64(n) => *n == value,
+            #[cfg(feature = "regex")]
+            Match::Regex(_) => self.matches_rendered(&value.to_string()),
+            _ => false,
+        }
+    }
+
+    fn matches_u64(&self, value: u64) -> bool {
+        match self {
+            Match::U64(n) => *n == value,
+            #[cfg(feature = "regex")]
+            Match::Regex(_) => self.matches_rendered(&value.to_string()),
+            _ => false,
+        }
+    }
+
+    fn matches_f64(&self, value: f64) -> bool {
+        match self {
+            Match::F64(n) => *n == value,
+            #[cfg(feature = "regex")]
+            Match::Regex(_) => self.matches_rendered(&value.to_string()),
+            _ => false,
+        }
+    }
 }
 
 impl FieldTransformLayer<()> {
@@ -90,10 +583,39 @@ impl FieldTransformLayer<()> {
         }
     }
 
+    /// Create a layer whose rule set can be swapped at runtime through the returned
+    /// [`Handle`], without rebuilding the subscriber.
+    ///
+    /// Reads on the hot path (every span/event record) take only a read lock, so steady
+    /// state logging is cheap; `Handle::reload`/`Handle::modify` take a write lock and are
+    /// meant to be called rarely (e.g. from an ops endpoint or a config-watcher thread).
+    pub fn new_with_handle() -> (FieldTransformLayer<ReloadableConfig>, Handle) {
+        let inner = Arc::new(RwLock::new(TransformConfig::new()));
+        let layer = FieldTransformLayer {
+            transforms: ReloadableConfig(inner.clone()),
+            _phantom: PhantomData,
+        };
+        (layer, Handle(inner))
+    }
+
+    /// Consuming alias for [`new_with_handle`](Self::new_with_handle), for callers that
+    /// prefer chaining off [`FieldTransformLayer::new`] the way the crate's own
+    /// `reload::Layer` is built, e.g. `FieldTransformLayer::new().with_reload()`.
+    pub fn with_reload(self) -> (FieldTransformLayer<ReloadableConfig>, Handle) {
+        Self::new_with_handle()
+    }
+
     /// Add transformations for a specific target pattern.
+    ///
+    /// `target_pattern` accepts the same `target[field]=level` grammar as
+    /// [`TargetRuleBuilder::new`]: a bare `"reqwest"` matches that target and everything
+    /// nested under it, `"sqlx[rows_affected]"` additionally requires the callsite to
+    /// declare a `rows_affected` field, and `"tokio::runtime=debug"` additionally requires
+    /// the event or span to be at `DEBUG` level or more verbose. When more than one rule
+    /// matches, the most specific one wins per-field (see [`merge_matching_rules`]).
     pub fn with_target_transform<F>(
         self,
-        target_pattern: &'static str,
+        target_pattern: impl Into<String>,
         builder: F,
     ) -> FieldTransformLayer<TransformConfig>
     where
@@ -108,11 +630,34 @@ impl FieldTransformLayer<()> {
             _phantom: PhantomData,
         }
     }
+
+    /// Add a rule gated by a custom predicate rather than (or in addition to) a target
+    /// pattern, so it only applies to spans/events `filter` accepts — e.g. only `WARN`-or-higher
+    /// events, or only events that carry a particular field.
+    ///
+    /// `filter` is evaluated against the callsite's [`Metadata`], the same information a
+    /// `tracing_subscriber` [`Filter`](crate::layer::Filter) sees in `enabled`/`event_enabled`;
+    /// pass a closure, or `Filter::enabled`/`event_enabled` wrapped in one, to reuse an
+    /// existing filter as a transform gate.
+    pub fn with_filter_transform<F, B>(self, filter: F, builder: B) -> FieldTransformLayer<TransformConfig>
+    where
+        F: Fn(&Metadata<'_>) -> bool + Send + Sync + 'static,
+        B: FnOnce(TargetRuleBuilder) -> TargetRuleBuilder,
+    {
+        let rule = builder(TargetRuleBuilder::new("").filter(filter)).build();
+
+        FieldTransformLayer {
+            transforms: TransformConfig {
+                target_rules: vec![rule],
+            },
+            _phantom: PhantomData,
+        }
+    }
 }
 
 impl FieldTransformLayer<TransformConfig> {
     /// Add additional transformations for another target pattern.
-    pub fn with_target_transform<F>(mut self, target_pattern: &'static str, builder: F) -> Self
+    pub fn with_target_transform<F>(mut self, target_pattern: impl Into<String>, builder: F) -> Self
     where
         F: FnOnce(TargetRuleBuilder) -> TargetRuleBuilder,
     {
@@ -120,110 +665,826 @@ impl FieldTransformLayer<TransformConfig> {
         self.transforms.target_rules.push(rule);
         self
     }
-}
 
-/// Builder for creating target-specific transformation rules.
-#[derive(Debug)]
-pub struct TargetRuleBuilder {
-    target_pattern: &'static str,
-    field_renames: Vec<(&'static str, &'static str)>,
-    hidden_fields: Vec<&'static str>,
-    field_transforms: Vec<FieldTransform>,
-}
+    /// Add additional transformations gated by a custom predicate; see
+    /// [`FieldTransformLayer::with_filter_transform`].
+    pub fn with_filter_transform<F, B>(mut self, filter: F, builder: B) -> Self
+    where
+        F: Fn(&Metadata<'_>) -> bool + Send + Sync + 'static,
+        B: FnOnce(TargetRuleBuilder) -> TargetRuleBuilder,
+    {
+        let rule = builder(TargetRuleBuilder::new("").filter(filter)).build();
+        self.transforms.target_rules.push(rule);
+        self
+    }
 
-impl TargetRuleBuilder {
-    fn new(target_pattern: &'static str) -> Self {
-        Self {
-            target_pattern,
-            field_renames: Vec::new(),
-            hidden_fields: Vec::new(),
-            field_transforms: Vec::new(),
+    /// Parse a layer configuration from a compact directive string, the same way an
+    /// [`EnvFilter`] is built from `RUST_LOG` directives.
+    ///
+    /// Two grammars are accepted; which one applies is chosen by whether `directives`
+    /// contains a `{`.
+    ///
+    /// ## `target=op;op;...`
+    ///
+    /// A comma-separated list of `target=op;op;...` directives:
+    ///
+    /// - `hide(field)` — hide `field` from the formatted output.
+    /// - `truncate(field,N)` — truncate `field` to `N` characters.
+    /// - `prefix(field,STR)` — prepend `STR` (followed by a space) to `field`.
+    /// - `rename(old,new)` — rename `old` to `new`.
+    /// - `transform(field,named)` — apply the transform registered under `named` in
+    ///   `named_transforms` to `field`.
+    ///
+    /// ## `target{rule,rule,...}`
+    ///
+    /// A semicolon-separated list of `target{rule,rule,...}` groups, e.g.
+    /// `kube{resource_name=>name,uid=trunc:8,resource_version=hide};containerd{size_bytes=bytes}`:
+    ///
+    /// - `old=>new` — rename `old` to `new`.
+    /// - `field=hide` — hide `field` from the formatted output.
+    /// - `field=trunc:N` — truncate `field` to `N` characters.
+    /// - `field=prefix:STR` — prepend `STR` (followed by a space) to `field`.
+    /// - `field=bytes` — render `field` as a human-readable byte size, e.g. `1536` becomes
+    ///   `1.5 KiB`.
+    ///
+    /// This grammar has no equivalent of `transform(field,named)`, so `named_transforms` is
+    /// ignored when it's used.
+    ///
+    /// In both grammars `target` is matched as a prefix, exactly like
+    /// [`with_target_transform`] does, and an empty directive string produces an identity
+    /// layer with no rules.
+    ///
+    /// A parse failure's [`DirectiveParseError::position`] is the byte offset of the
+    /// offending directive or group within `directives`, so a caller can point back at
+    /// exactly which one failed.
+    ///
+    /// [`EnvFilter`]: crate::EnvFilter
+    /// [`with_target_transform`]: FieldTransformLayer::with_target_transform
+    pub fn from_str(
+        directives: &str,
+        named_transforms: &HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>>,
+    ) -> Result<Self, DirectiveParseError> {
+        if directives.contains('{') {
+            return Ok(Self {
+                transforms: TransformConfig {
+                    target_rules: parse_grouped_directives(directives)?,
+                },
+                _phantom: PhantomData,
+            });
         }
-    }
 
-    /// Rename a field.
-    pub fn rename_field(mut self, from: &'static str, to: &'static str) -> Self {
-        self.field_renames.push((from, to));
-        self
+        let mut target_rules = Vec::new();
+        for (offset, directive) in byte_offsets(directives, ',') {
+            let trimmed = directive.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let trimmed_offset = offset + leading_whitespace(directive);
+            target_rules.push(parse_directive(trimmed, trimmed_offset, named_transforms)?);
+        }
+        Ok(Self {
+            transforms: TransformConfig { target_rules },
+            _phantom: PhantomData,
+        })
     }
 
-    /// Hide a field from display.
-    pub fn hide_field(mut self, field: &'static str) -> Self {
-        self.hidden_fields.push(field);
-        self
+    /// Build a layer from the directive string held in the named environment variable
+    /// (e.g. `BT_TRANSFORM`), the same way `EnvFilter::from_default_env` reads `RUST_LOG`.
+    ///
+    /// A missing or empty environment variable yields an identity layer rather than an
+    /// error, so operators can omit it entirely in environments that don't need
+    /// retuning.
+    pub fn from_env(
+        var: &str,
+        named_transforms: &HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>>,
+    ) -> Result<Self, DirectiveParseError> {
+        match std::env::var(var) {
+            Ok(ref directives) if !directives.trim().is_empty() => {
+                Self::from_str(directives, named_transforms)
+            }
+            _ => Ok(Self {
+                transforms: TransformConfig {
+                    target_rules: Vec::new(),
+                },
+                _phantom: PhantomData,
+            }),
+        }
     }
+}
 
-    /// Truncate a field to the specified length.
-    pub fn truncate_field(mut self, field: &'static str, max_len: usize) -> Self {
-        self.field_transforms.push(FieldTransform {
-            field_name: field,
-            transform_type: TransformType::Truncate(max_len),
-        });
-        self
-    }
+/// An error produced when a [`FieldTransformLayer`] directive string fails to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectiveParseError {
+    message: String,
+    position: usize,
+}
 
-    /// Add a static prefix to a field.
-    pub fn prefix_field(mut self, field: &'static str, prefix: &'static str) -> Self {
-        self.field_transforms.push(FieldTransform {
-            field_name: field,
-            transform_type: TransformType::Prefix(prefix),
-        });
-        self
+impl DirectiveParseError {
+    /// The byte offset within the original directive string where parsing failed — the start
+    /// of the `target=op;op;...` directive for a directive-level error, or the start of the
+    /// specific `op(args)` for an op-level one.
+    pub fn position(&self) -> usize {
+        self.position
     }
+}
 
-    /// Apply a custom transformation to a field.
-    pub fn transform_field(mut self, field: &'static str, transform: fn(&str) -> String) -> Self {
-        self.field_transforms.push(FieldTransform {
-            field_name: field,
-            transform_type: TransformType::Custom(transform),
-        });
-        self
+impl fmt::Display for DirectiveParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid field transform directive at byte {}: {}",
+            self.position, self.message
+        )
     }
+}
 
-    /// Build the target rule (internal method).
-    pub fn build(self) -> TargetRule {
-        TargetRule {
-            target_pattern: self.target_pattern,
-            field_renames: self.field_renames,
-            hidden_fields: self.hidden_fields,
-            field_transforms: self.field_transforms,
+impl std::error::Error for DirectiveParseError {}
+
+fn leak_str(s: &str) -> &'static str {
+    Box::leak(s.to_string().into_boxed_str())
+}
+
+/// Splits `s` on each `sep` outside parentheses (so the comma in `truncate(uid,8)` stays
+/// inside its op), pairing each piece with its byte offset within `s` — unlike
+/// [`str::split`] alone, which discards that position once the string is sliced up.
+fn byte_offsets(s: &str, sep: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut offset = 0;
+    let mut depth = 0usize;
+    s.split(move |c: char| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
         }
+        c == sep && depth == 0
+    })
+    .map(move |piece| {
+        let start = offset;
+        offset += piece.len() + sep.len_utf8();
+        (start, piece)
+    })
+}
+
+/// The number of leading ASCII/Unicode whitespace bytes `str::trim_start` would strip from `s`.
+fn leading_whitespace(s: &str) -> usize {
+    s.len() - s.trim_start().len()
+}
+
+/// Builds a [`Field`] named `new_name` on `original`'s callsite, so a renamed field can be
+/// forwarded to a visitor under its new name instead of the one tracing recorded it under.
+///
+/// A `Field`'s name is otherwise fixed by the `FieldSet` its callsite registered at compile
+/// time, so this is the only way to make a rename show up as a different key rather than
+/// just a relabeled value.
+///
+/// Every call leaks the `new_name` slice and the `FieldSet` it's boxed into, since a `Field`
+/// borrows them for `'static`. Call this at most once per `(callsite, new_name)` pair — see
+/// [`resolve_field`], which caches through [`rename_field_cached`] rather than calling this
+/// directly on every recorded field.
+fn rename_field(original: &Field, new_name: &'static str) -> Field {
+    let names: &'static [&'static str] = Box::leak(vec![new_name].into_boxed_slice());
+    let fields = FieldSet::new(names, original.callsite());
+    fields
+        .field(new_name)
+        .expect("FieldSet was just built with exactly this one name")
+}
+
+/// Process-wide cache of renamed `Field`s, keyed by the original field's callsite and the
+/// rename target, so [`rename_field_cached`] leaks at most one `Field` per distinct
+/// `(callsite, new_name)` pair rather than once per recorded value.
+static RENAMED_FIELD_CACHE: OnceLock<Mutex<HashMap<(Identifier, String), Field>>> = OnceLock::new();
+
+/// Like [`rename_field`], but only leaks a new `Field` the first time a given
+/// `(original.callsite(), new_name)` pair is requested; every later call for the same pair
+/// returns a clone of the cached `Field`.
+///
+/// `resolve_field` calls this for every renamed field on every recorded event or span-record
+/// through [`TransformFormatFields`], so without this cache a long-running service would leak
+/// two allocations per logged value forever.
+fn rename_field_cached(original: &Field, new_name: &str) -> Field {
+    let cache = RENAMED_FIELD_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    let key = (original.callsite(), new_name.to_string());
+
+    let mut cache = cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(field) = cache.get(&key) {
+        return field.clone();
     }
+
+    let field = rename_field(original, leak_str(new_name));
+    cache.insert(key, field.clone());
+    field
 }
 
-/// A field visitor that applies transformations during recording.
-struct TransformingVisitor<'a> {
-    writer: Writer<'a>,
-    rule: &'a TargetRule,
+/// Returns `true` when `pattern` matches `target` at a `::`-delimited path boundary, the way
+/// tracing targets are conventionally structured into modules. `"http"` matches `"http"` and
+/// `"http::client"`, but not `"http_client"` or `"my_http"` — unlike a plain
+/// [`str::contains`], which would wrongly match all three.
+fn target_matches(target: &str, pattern: &str) -> bool {
+    target == pattern
+        || target
+            .strip_prefix(pattern)
+            .is_some_and(|rest| rest.starts_with("::"))
 }
 
-impl<'a> TransformingVisitor<'a> {
-    fn new(writer: Writer<'a>, rule: &'a TargetRule) -> Self {
-        Self { writer, rule }
+/// Splits a directive pattern such as `"reqwest::client"`, `"sqlx[rows_affected]"`, or
+/// `"tokio::runtime[task_id]=info"` into its target path, optional field scope, and
+/// optional level threshold, mirroring the grammar `EnvFilter`/`Targets` directives use.
+///
+/// An unparsable `=level` suffix is left attached to the target path rather than rejected
+/// outright, since a target legitimately containing `=` is more likely than a typo'd level.
+fn parse_target_pattern(pattern: &str) -> (String, Option<String>, Option<Level>) {
+    let (rest, level_threshold) = match pattern.rsplit_once('=') {
+        Some((rest, level)) => match level.trim().parse::<Level>() {
+            Ok(level) => (rest, Some(level)),
+            Err(_) => (pattern, None),
+        },
+        None => (pattern, None),
+    };
+
+    match rest.split_once('[') {
+        Some((target, tail)) => match tail.strip_suffix(']') {
+            Some(field) => (target.to_string(), Some(field.to_string()), level_threshold),
+            None => (rest.to_string(), None, level_threshold),
+        },
+        None => (rest.to_string(), None, level_threshold),
     }
 }
 
-impl Visit for TransformingVisitor<'_> {
-    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
-        let field_name = field.name();
+/// Returns `true` when `rule` should be considered for `metadata`: its target path matches
+/// (see [`target_matches`]) — or it has no target pattern at all, which
+/// [`FieldTransformLayer::with_filter_transform`] uses for rules gated purely by a custom
+/// filter — its field scope (if any) is declared on this callsite, its level threshold (if
+/// any) is at least as severe as `metadata`'s level, and its custom filter (if any) accepts
+/// `metadata`.
+fn rule_applies(rule: &TargetRule, metadata: &Metadata<'_>) -> bool {
+    (rule.target_pattern.is_empty() || target_matches(metadata.target(), &rule.target_pattern))
+        && rule
+            .field_scope
+            .as_deref()
+            .map_or(true, |field| metadata.fields().field(field).is_some())
+        && rule
+            .level_threshold
+            .map_or(true, |threshold| *metadata.level() >= threshold)
+        && rule
+            .custom_filter
+            .as_ref()
+            .map_or(true, |filter| filter(metadata))
+}
 
-        // Check if field should be hidden
-        if self
-            .rule
-            .hidden_fields
-            .iter()
-            .any(|&hidden| hidden == field_name)
-        {
-            return;
+/// Collects every rule in `rules` that applies to `metadata` (see [`rule_applies`]) and
+/// merges them into a single effective rule.
+///
+/// Rules are folded in order of increasing specificity — target pattern length first, then
+/// whether the rule carries a field scope and/or level threshold — so a more specific rule
+/// (e.g. `"http::client=debug"`) overrides a less specific one (e.g. `"http"`) for any field
+/// they both touch, while a field only covered by one of them still gets that rule's
+/// treatment.
+fn merge_matching_rules(rules: &[TargetRule], metadata: &Metadata<'_>) -> Option<TargetRule> {
+    let mut matches: Vec<&TargetRule> = rules
+        .iter()
+        .filter(|rule| rule_applies(rule, metadata))
+        .collect();
+    if matches.is_empty() {
+        return None;
+    }
+    matches.sort_by_key(|rule| {
+        (
+            rule.target_pattern.len(),
+            rule.field_scope.is_some(),
+            rule.level_threshold.is_some(),
+        )
+    });
+
+    let mut merged = TargetRule {
+        target_pattern: matches.last().expect("matches is non-empty").target_pattern.clone(),
+        field_scope: matches.last().expect("matches is non-empty").field_scope.clone(),
+        level_threshold: matches.last().expect("matches is non-empty").level_threshold,
+        custom_filter: matches.last().expect("matches is non-empty").custom_filter.clone(),
+        field_renames: Vec::new(),
+        hidden_fields: Vec::new(),
+        field_transforms: Vec::new(),
+        value_redactions: Vec::new(),
+    };
+    for rule in matches {
+        for hidden in &rule.hidden_fields {
+            if !merged.hidden_fields.contains(hidden) {
+                merged.hidden_fields.push(hidden.clone());
+            }
+        }
+        for (from, to) in &rule.field_renames {
+            match merged.field_renames.iter_mut().find(|(f, _)| f == from) {
+                Some(existing) => existing.1 = to.clone(),
+                None => merged.field_renames.push((from.clone(), to.clone())),
+            }
         }
+        for transform in &rule.field_transforms {
+            match merged
+                .field_transforms
+                .iter_mut()
+                .find(|t| t.field_name == transform.field_name)
+            {
+                Some(existing) => *existing = transform.clone(),
+                None => merged.field_transforms.push(transform.clone()),
+            }
+        }
+        merged.value_redactions.extend(rule.value_redactions.iter().cloned());
+    }
+    Some(merged)
+}
 
-        // Check for field rename
-        let display_name = self
-            .rule
+/// Parses the `target{rule,rule,...};target{rule,rule,...}` grammar — see
+/// [`FieldTransformLayer::from_str`].
+fn parse_grouped_directives(directives: &str) -> Result<Vec<TargetRule>, DirectiveParseError> {
+    let mut target_rules = Vec::new();
+    for (offset, group) in byte_offsets(directives, ';') {
+        let trimmed = group.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let trimmed_offset = offset + leading_whitespace(group);
+        target_rules.push(parse_group(trimmed, trimmed_offset)?);
+    }
+    Ok(target_rules)
+}
+
+fn parse_group(group: &str, offset: usize) -> Result<TargetRule, DirectiveParseError> {
+    let open = group.find('{').ok_or_else(|| DirectiveParseError {
+        message: format!(
+            "group `{}` is missing the `target{{rule,rule,...}}` braces",
+            group
+        ),
+        position: offset,
+    })?;
+    let target = &group[..open];
+    let rules = group[open + 1..].strip_suffix('}').ok_or_else(|| DirectiveParseError {
+        message: format!("group `{}` is missing a closing `}}`", group),
+        position: offset,
+    })?;
+    let rules_offset = offset + open + 1;
+
+    let mut builder = TargetRuleBuilder::new(target.trim());
+    for (rule_offset, rule) in byte_offsets(rules, ',') {
+        let rule = rule.trim();
+        if rule.is_empty() {
+            continue;
+        }
+        builder = parse_grouped_rule(builder, rule, rules_offset + rule_offset)?;
+    }
+    Ok(builder.build())
+}
+
+fn parse_grouped_rule(
+    builder: TargetRuleBuilder,
+    rule: &str,
+    offset: usize,
+) -> Result<TargetRuleBuilder, DirectiveParseError> {
+    if let Some((old, new)) = rule.split_once("=>") {
+        return Ok(builder.rename_field(old.trim(), new.trim()));
+    }
+
+    let (field, value) = rule.split_once('=').ok_or_else(|| DirectiveParseError {
+        message: format!(
+            "rule `{}` is missing the `old=>new` or `field=...` separator",
+            rule
+        ),
+        position: offset,
+    })?;
+    let field = field.trim();
+    let value = value.trim();
+
+    if let Some(len) = value.strip_prefix("trunc:") {
+        let len: usize = len.trim().parse().map_err(|_| DirectiveParseError {
+            message: format!("`trunc` length `{}` is not a valid number", len.trim()),
+            position: offset,
+        })?;
+        return Ok(builder.truncate_field(field, len));
+    }
+    if let Some(prefix) = value.strip_prefix("prefix:") {
+        return Ok(builder.prefix_field(field, prefix.trim()));
+    }
+    match value {
+        "hide" => Ok(builder.hide_field(field)),
+        "bytes" => Ok(builder.transform_field(field, format_bytes)),
+        other => Err(DirectiveParseError {
+            message: format!(
+                "unknown rule `{}`; expected `old=>new`, `field=hide`, `field=trunc:N`, \
+                 `field=prefix:STR`, or `field=bytes`",
+                other
+            ),
+            position: offset,
+        }),
+    }
+}
+
+/// Formats a byte count as a human-readable size with binary (1024-based) units, for the
+/// `field=bytes` directive rule.
+///
+/// A value that doesn't parse as a `u64` is passed through unchanged, so applying `bytes` to
+/// a field that's merely usually numeric won't panic on the odd malformed one.
+fn format_bytes(value: &str) -> String {
+    let Ok(bytes) = value.parse::<u64>() else {
+        return value.to_string();
+    };
+
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+fn parse_directive(
+    directive: &str,
+    offset: usize,
+    named_transforms: &HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>>,
+) -> Result<TargetRule, DirectiveParseError> {
+    let (target, ops) = directive.split_once('=').ok_or_else(|| DirectiveParseError {
+        message: format!(
+            "directive `{}` is missing the `target=op;op;...` separator",
+            directive
+        ),
+        position: offset,
+    })?;
+    let ops_offset = offset + target.len() + 1;
+
+    let mut builder = TargetRuleBuilder::new(target.trim());
+    for (op_offset, op) in byte_offsets(ops, ';') {
+        let op = op.trim();
+        if op.is_empty() {
+            continue;
+        }
+        builder = parse_op(builder, op, ops_offset + op_offset, named_transforms)?;
+    }
+    Ok(builder.build())
+}
+
+fn parse_op(
+    builder: TargetRuleBuilder,
+    op: &str,
+    offset: usize,
+    named_transforms: &HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>>,
+) -> Result<TargetRuleBuilder, DirectiveParseError> {
+    let (name, args) = op
+        .split_once('(')
+        .and_then(|(name, rest)| rest.strip_suffix(')').map(|args| (name, args)))
+        .ok_or_else(|| DirectiveParseError {
+            message: format!("op `{}` is not of the form `name(args)`", op),
+            position: offset,
+        })?;
+
+    match name {
+        "hide" => Ok(builder.hide_field(args.trim())),
+        "truncate" => {
+            let (field, len) = args.split_once(',').ok_or_else(|| DirectiveParseError {
+                message: format!("`truncate` expects `field,N`, got `{}`", args),
+                position: offset,
+            })?;
+            let len: usize = len.trim().parse().map_err(|_| DirectiveParseError {
+                message: format!("`truncate` length `{}` is not a valid number", len.trim()),
+                position: offset,
+            })?;
+            Ok(builder.truncate_field(field.trim(), len))
+        }
+        "prefix" => {
+            let (field, prefix) = args.split_once(',').ok_or_else(|| DirectiveParseError {
+                message: format!("`prefix` expects `field,STR`, got `{}`", args),
+                position: offset,
+            })?;
+            Ok(builder.prefix_field(field.trim(), prefix.trim()))
+        }
+        "rename" => {
+            let (from, to) = args.split_once(',').ok_or_else(|| DirectiveParseError {
+                message: format!("`rename` expects `old,new`, got `{}`", args),
+                position: offset,
+            })?;
+            Ok(builder.rename_field(from.trim(), to.trim()))
+        }
+        "transform" => {
+            let (field, named) = args.split_once(',').ok_or_else(|| DirectiveParseError {
+                message: format!("`transform` expects `field,named`, got `{}`", args),
+                position: offset,
+            })?;
+            let named = named.trim();
+            let func = named_transforms.get(named).ok_or_else(|| DirectiveParseError {
+                message: format!("no transform named `{}` was registered", named),
+                position: offset,
+            })?;
+            Ok(builder.transform_field(field.trim(), {
+                let func = func.clone();
+                move |value: &str| func(value)
+            }))
+        }
+        other => Err(DirectiveParseError {
+            message: format!(
+                "unknown op `{}`; expected one of hide, truncate, prefix, rename, transform",
+                other
+            ),
+            position: offset,
+        }),
+    }
+}
+
+/// Builder for creating target-specific transformation rules.
+pub struct TargetRuleBuilder {
+    target_pattern: String,
+    field_scope: Option<String>,
+    level_threshold: Option<Level>,
+    custom_filter: Option<Arc<dyn Fn(&Metadata<'_>) -> bool + Send + Sync>>,
+    field_renames: Vec<(String, String)>,
+    hidden_fields: Vec<String>,
+    field_transforms: Vec<FieldTransform>,
+    value_redactions: Vec<ValueRedaction>,
+}
+
+impl fmt::Debug for TargetRuleBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TargetRuleBuilder")
+            .field("target_pattern", &self.target_pattern)
+            .field("field_scope", &self.field_scope)
+            .field("level_threshold", &self.level_threshold)
+            .field("custom_filter", &self.custom_filter.as_ref().map(|_| ".."))
+            .field("field_renames", &self.field_renames)
+            .field("hidden_fields", &self.hidden_fields)
+            .field("field_transforms", &self.field_transforms)
+            .field("value_redactions", &self.value_redactions)
+            .finish()
+    }
+}
+
+impl TargetRuleBuilder {
+    /// Parses `target_pattern` using the same directive grammar as `EnvFilter`/`Targets`:
+    /// an optional `[field]` scope restricting the rule to callsites that declare that
+    /// field, and an optional `=level` suffix restricting it to that level or more verbose.
+    ///
+    /// `"reqwest::client"`, `"sqlx[rows_affected]"`, and `"tokio::runtime[task_id]=info"`
+    /// are all valid patterns. An empty pattern matches every target; combine it with
+    /// [`filter`](Self::filter) to gate a rule purely by a custom predicate, as
+    /// [`FieldTransformLayer::with_filter_transform`] does.
+    fn new(target_pattern: impl Into<String>) -> Self {
+        let (target_pattern, field_scope, level_threshold) =
+            parse_target_pattern(&target_pattern.into());
+        Self {
+            target_pattern,
+            field_scope,
+            level_threshold,
+            custom_filter: None,
+            field_renames: Vec::new(),
+            hidden_fields: Vec::new(),
+            field_transforms: Vec::new(),
+            value_redactions: Vec::new(),
+        }
+    }
+
+    /// Restricts this rule to spans/events that `filter` accepts, in addition to any target
+    /// pattern, field scope, or level threshold already configured.
+    ///
+    /// When more than one rule matches a given callsite (see [`merge_matching_rules`]), the
+    /// most specific rule's filter — like its level threshold — wins for the merged rule.
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Metadata<'_>) -> bool + Send + Sync + 'static,
+    {
+        self.custom_filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Rename a field.
+    pub fn rename_field(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.field_renames.push((from.into(), to.into()));
+        self
+    }
+
+    /// Hide a field from display.
+    pub fn hide_field(mut self, field: impl Into<String>) -> Self {
+        self.hidden_fields.push(field.into());
+        self
+    }
+
+    /// Truncate a field to the specified length.
+    pub fn truncate_field(mut self, field: impl Into<String>, max_len: usize) -> Self {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::Truncate(max_len),
+        });
+        self
+    }
+
+    /// Add a static prefix to a field.
+    pub fn prefix_field(mut self, field: impl Into<String>, prefix: impl Into<String>) -> Self {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::Prefix(prefix.into()),
+        });
+        self
+    }
+
+    /// Apply a custom transformation to a field.
+    pub fn transform_field<F>(mut self, field: impl Into<String>, transform: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::Custom(Arc::new(transform)),
+        });
+        self
+    }
+
+    /// Apply a transformation to a field only when its recorded value matches `condition`.
+    ///
+    /// Unlike [`transform_field`](Self::transform_field), a field whose value does not
+    /// match `condition` passes through unchanged, with no allocation.
+    pub fn transform_field_if<F>(mut self, field: impl Into<String>, condition: Match, transform: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::Conditional(condition, Arc::new(transform)),
+        });
+        self
+    }
+
+    /// Apply a type-preserving transform that receives the original recorded value's kind
+    /// as a [`TransformValue`] and returns a (possibly different) typed value, instead of
+    /// forcing a round trip through `&str`.
+    ///
+    /// Unlike [`transform_field`](Self::transform_field), the result is forwarded to the
+    /// inner formatter under its own type — a [`TransformValue::F64`] result still shows
+    /// up as a JSON number, not a quoted string.
+    pub fn transform_value<F>(mut self, field: impl Into<String>, transform: F) -> Self
+    where
+        F: Fn(TransformValue) -> TransformValue + Send + Sync + 'static,
+    {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::Typed(Arc::new(transform)),
+        });
+        self
+    }
+
+    /// Apply a transform to `field` that can also see every other field recorded on the same
+    /// event, via the [`FieldContext`] passed as the closure's second argument — for example,
+    /// recoloring `resource_name` based on the value of a separate `phase` field.
+    ///
+    /// Sibling fields are only visible through [`TransformFormatFields`] (i.e. when this rule
+    /// set is installed as a formatter's `.fmt_fields(...)`, not only as a
+    /// [`FieldTransformLayer`]): producing the context requires buffering every field on the
+    /// event before any of them reach the real formatter, which only the fmt-fields path does.
+    /// In a [`FieldTransformLayer`]'s own span-field text the closure runs with an empty
+    /// [`FieldContext`]. Context transforms run
+    /// after every simple (non-context) [`transform_field`](Self::transform_field)-style
+    /// transform has already been applied, so a context transform sees its sibling fields'
+    /// *displayed* values, not their raw recorded ones.
+    pub fn transform_with_context<F>(mut self, field: impl Into<String>, transform: F) -> Self
+    where
+        F: Fn(&str, &FieldContext<'_>) -> String + Send + Sync + 'static,
+    {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::WithContext(Arc::new(transform)),
+        });
+        self
+    }
+
+    /// Mask every value of `field` matched by `matcher`, replacing it entirely with `*`.
+    ///
+    /// Use [`redact_matching`](Self::redact_matching) instead when a tail of the value
+    /// (e.g. the last 4 digits of a card number) should stay visible.
+    pub fn redact_field(mut self, field: impl Into<String>, matcher: RedactMatcher) -> Self {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::Redact {
+                matcher,
+                keep_last: 0,
+            },
+        });
+        self
+    }
+
+    /// Mask every substring of `field`'s value matching `regex`, keeping the last
+    /// `keep_last` characters of each match visible.
+    #[cfg(feature = "regex")]
+    pub fn redact_matching(
+        mut self,
+        field: impl Into<String>,
+        regex: regex::Regex,
+        keep_last: usize,
+    ) -> Self {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::Redact {
+                matcher: RedactMatcher::Regex(regex),
+                keep_last,
+            },
+        });
+        self
+    }
+
+    /// Redact every recorded value of `field` unconditionally, according to `mode` — unlike
+    /// [`redact_field`](Self::redact_field), which only masks values matched by a
+    /// [`RedactMatcher`], every value of `field` is rewritten regardless of its content.
+    ///
+    /// This is the field's per-field transform, and a field carries at most one: when this and
+    /// a `truncate_field`/`prefix_field` are configured for the same field, only the one added
+    /// last is applied, so a redacted value is not truncated or prefixed afterward.
+    pub fn redact_field_with_mode(mut self, field: impl Into<String>, mode: RedactMode) -> Self {
+        self.field_transforms.push(FieldTransform {
+            field_name: field.into(),
+            transform_type: TransformType::RedactMode(mode),
+        });
+        self
+    }
+
+    /// Redact every recorded field whose *value* (not name) satisfies `predicate`, according
+    /// to `mode` — e.g. catching `Authorization: Bearer ...` wherever it shows up, regardless
+    /// of which field carries it.
+    ///
+    /// Runs after hiding (a hidden field is omitted without being tested) and before any other
+    /// per-field transform for the matched field, so a field that's both caught here and has
+    /// its own `truncate_field` rule is truncated *after* redaction, never before. Values
+    /// recorded as strings or through `Debug` are tested; numeric and boolean values are only
+    /// tested for a rule that also carries a
+    /// [`transform_with_context`](Self::transform_with_context) transform.
+    pub fn redact_value_matching<F>(mut self, predicate: F, mode: RedactMode) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.value_redactions.push(ValueRedaction {
+            predicate: Arc::new(predicate),
+            mode,
+        });
+        self
+    }
+
+    /// Build the target rule (internal method).
+    pub fn build(self) -> TargetRule {
+        TargetRule {
+            target_pattern: self.target_pattern,
+            field_scope: self.field_scope,
+            level_threshold: self.level_threshold,
+            custom_filter: self.custom_filter,
+            field_renames: self.field_renames,
+            hidden_fields: self.hidden_fields,
+            field_transforms: self.field_transforms,
+            value_redactions: self.value_redactions,
+        }
+    }
+}
+
+/// A field visitor that applies transformations during recording.
+struct TransformingVisitor<'a> {
+    writer: Writer<'a>,
+    rule: &'a TargetRule,
+}
+
+impl<'a> TransformingVisitor<'a> {
+    fn new(writer: Writer<'a>, rule: &'a TargetRule) -> Self {
+        Self { writer, rule }
+    }
+}
+
+impl Visit for TransformingVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let field_name = field.name();
+
+        // Check if field should be hidden
+        if self
+            .rule
+            .hidden_fields
+            .iter()
+            .any(|hidden| hidden == field_name)
+        {
+            return;
+        }
+
+        // Cross-field value-predicate redaction (see `redact_value_matching`) runs before any
+        // per-field transform, and even on fields with no transform of their own configured.
+        let redacted = if self.rule.value_redactions.is_empty() {
+            None
+        } else {
+            match apply_value_redactions_aware(self.rule, &format!("{:?}", value), true) {
+                Some(Some(redacted)) => Some(redacted),
+                Some(None) => return, // RedactMode::Drop
+                None => None,
+            }
+        };
+
+        // Check for field rename
+        let display_name = self
+            .rule
             .field_renames
             .iter()
-            .find(|(from, _)| *from == field_name)
-            .map(|(_, to)| *to)
+            .find(|(from, _)| from == field_name)
+            .map(|(_, to)| to.as_str())
             .unwrap_or(field_name);
 
         // Check for field transformation
@@ -233,21 +1494,36 @@ impl Visit for TransformingVisitor<'_> {
             .iter()
             .find(|t| t.field_name == field_name)
         {
-            let value_str = format!("{:?}", value);
+            if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+                return;
+            }
+            let value_str = redacted.unwrap_or_else(|| format!("{:?}", value));
             let transformed_value = match &transform.transform_type {
-                TransformType::Truncate(max_len) => {
-                    if value_str.len() > *max_len {
-                        format!("{}...", &value_str[..*max_len])
+                TransformType::Truncate(max_len) => truncate_chars(&value_str, *max_len),
+                TransformType::Prefix(prefix) => {
+                    format!("{} {}", prefix, value_str)
+                }
+                TransformType::Custom(func) => func(&value_str),
+                TransformType::Conditional(condition, func) => {
+                    if condition.matches_rendered(&value_str) {
+                        func(&value_str)
                     } else {
                         value_str
                     }
                 }
-                TransformType::Prefix(prefix) => {
-                    format!("{} {}", prefix, value_str)
+                TransformType::Redact { matcher, keep_last } => {
+                    matcher.redact_debug(&value_str, *keep_last).unwrap_or(value_str)
                 }
-                TransformType::Custom(func) => func(&value_str),
+                TransformType::RedactMode(mode) => mode.apply(&value_str).unwrap_or(value_str),
+                TransformType::Typed(func) => func(TransformValue::Debug(value_str.clone())).render(),
+                // This visitor writes each field directly as it's visited, with no buffering,
+                // so there's no sibling-field context to offer; see
+                // `TargetRuleBuilder::transform_with_context`'s docs.
+                TransformType::WithContext(func) => func(&value_str, &FieldContext::new(&[], "")),
             };
             let _ = write!(self.writer, "{}={}", display_name, transformed_value);
+        } else if let Some(value_str) = redacted {
+            let _ = write!(self.writer, "{}={}", display_name, value_str);
         } else {
             let _ = write!(self.writer, "{}={:?}", display_name, value);
         }
@@ -261,18 +1537,29 @@ impl Visit for TransformingVisitor<'_> {
             .rule
             .hidden_fields
             .iter()
-            .any(|&hidden| hidden == field_name)
+            .any(|hidden| hidden == field_name)
         {
             return;
         }
 
+        // Cross-field value-predicate redaction runs first; see `record_debug` above.
+        let redacted = if self.rule.value_redactions.is_empty() {
+            None
+        } else {
+            match apply_value_redactions(self.rule, value) {
+                Some(Some(redacted)) => Some(redacted),
+                Some(None) => return, // RedactMode::Drop
+                None => None,
+            }
+        };
+
         // Check for field rename
         let display_name = self
             .rule
             .field_renames
             .iter()
-            .find(|(from, _)| *from == field_name)
-            .map(|(_, to)| *to)
+            .find(|(from, _)| from == field_name)
+            .map(|(_, to)| to.as_str())
             .unwrap_or(field_name);
 
         // Check for field transformation
@@ -282,20 +1569,35 @@ impl Visit for TransformingVisitor<'_> {
             .iter()
             .find(|t| t.field_name == field_name)
         {
+            if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+                return;
+            }
+            let value_ref: &str = redacted.as_deref().unwrap_or(value);
             let transformed_value = match &transform.transform_type {
-                TransformType::Truncate(max_len) => {
-                    if value.len() > *max_len {
-                        format!("{}...", &value[..*max_len])
+                TransformType::Truncate(max_len) => truncate_chars(value_ref, *max_len),
+                TransformType::Prefix(prefix) => {
+                    format!("{} {}", prefix, value_ref)
+                }
+                TransformType::Custom(func) => func(value_ref),
+                TransformType::Conditional(condition, func) => {
+                    if condition.matches_rendered(value_ref) {
+                        func(value_ref)
                     } else {
-                        value.to_string()
+                        value_ref.to_string()
                     }
                 }
-                TransformType::Prefix(prefix) => {
-                    format!("{} {}", prefix, value)
+                TransformType::Redact { matcher, keep_last } => {
+                    matcher.redact(value_ref, *keep_last).unwrap_or_else(|| value_ref.to_string())
                 }
-                TransformType::Custom(func) => func(value),
+                TransformType::RedactMode(mode) => {
+                    mode.apply(value_ref).unwrap_or_else(|| value_ref.to_string())
+                }
+                TransformType::Typed(func) => func(TransformValue::Str(value_ref.to_string())).render(),
+                TransformType::WithContext(func) => func(value_ref, &FieldContext::new(&[], "")),
             };
             let _ = write!(self.writer, "{}={}", display_name, transformed_value);
+        } else if let Some(redacted) = redacted {
+            let _ = write!(self.writer, "{}={}", display_name, redacted);
         } else {
             let _ = write!(self.writer, "{}={}", display_name, value);
         }
@@ -307,15 +1609,45 @@ impl Visit for TransformingVisitor<'_> {
             .rule
             .hidden_fields
             .iter()
-            .any(|&hidden| hidden == field_name)
+            .any(|hidden| hidden == field_name)
         {
             let display_name = self
                 .rule
                 .field_renames
                 .iter()
-                .find(|(from, _)| *from == field_name)
-                .map(|(_, to)| *to)
+                .find(|(from, _)| from == field_name)
+                .map(|(_, to)| to.as_str())
                 .unwrap_or(field_name);
+            match self
+                .rule
+                .field_transforms
+                .iter()
+                .find(|t| t.field_name == field_name)
+                .map(|t| &t.transform_type)
+            {
+                Some(TransformType::Conditional(condition, func)) if condition.matches_i64(value) => {
+                    let _ = write!(self.writer, "{}={}", display_name, func(&value.to_string()));
+                    return;
+                }
+                Some(TransformType::Redact { matcher, keep_last }) => {
+                    if let Some(redacted) = matcher.redact(&value.to_string(), *keep_last) {
+                        let _ = write!(self.writer, "{}={}", display_name, redacted);
+                        return;
+                    }
+                }
+                Some(TransformType::RedactMode(mode)) => {
+                    if let Some(redacted) = mode.apply(&value.to_string()) {
+                        let _ = write!(self.writer, "{}={}", display_name, redacted);
+                    }
+                    return;
+                }
+                Some(TransformType::Typed(func)) => {
+                    let rendered = func(TransformValue::I64(value)).render();
+                    let _ = write!(self.writer, "{}={}", display_name, rendered);
+                    return;
+                }
+                _ => {}
+            }
             let _ = write!(self.writer, "{}={}", display_name, value);
         }
     }
@@ -326,15 +1658,45 @@ impl Visit for TransformingVisitor<'_> {
             .rule
             .hidden_fields
             .iter()
-            .any(|&hidden| hidden == field_name)
+            .any(|hidden| hidden == field_name)
         {
             let display_name = self
                 .rule
                 .field_renames
                 .iter()
-                .find(|(from, _)| *from == field_name)
-                .map(|(_, to)| *to)
+                .find(|(from, _)| from == field_name)
+                .map(|(_, to)| to.as_str())
                 .unwrap_or(field_name);
+            match self
+                .rule
+                .field_transforms
+                .iter()
+                .find(|t| t.field_name == field_name)
+                .map(|t| &t.transform_type)
+            {
+                Some(TransformType::Conditional(condition, func)) if condition.matches_u64(value) => {
+                    let _ = write!(self.writer, "{}={}", display_name, func(&value.to_string()));
+                    return;
+                }
+                Some(TransformType::Redact { matcher, keep_last }) => {
+                    if let Some(redacted) = matcher.redact(&value.to_string(), *keep_last) {
+                        let _ = write!(self.writer, "{}={}", display_name, redacted);
+                        return;
+                    }
+                }
+                Some(TransformType::RedactMode(mode)) => {
+                    if let Some(redacted) = mode.apply(&value.to_string()) {
+                        let _ = write!(self.writer, "{}={}", display_name, redacted);
+                    }
+                    return;
+                }
+                Some(TransformType::Typed(func)) => {
+                    let rendered = func(TransformValue::U64(value)).render();
+                    let _ = write!(self.writer, "{}={}", display_name, rendered);
+                    return;
+                }
+                _ => {}
+            }
             let _ = write!(self.writer, "{}={}", display_name, value);
         }
     }
@@ -345,15 +1707,45 @@ impl Visit for TransformingVisitor<'_> {
             .rule
             .hidden_fields
             .iter()
-            .any(|&hidden| hidden == field_name)
+            .any(|hidden| hidden == field_name)
         {
             let display_name = self
                 .rule
                 .field_renames
                 .iter()
-                .find(|(from, _)| *from == field_name)
-                .map(|(_, to)| *to)
+                .find(|(from, _)| from == field_name)
+                .map(|(_, to)| to.as_str())
                 .unwrap_or(field_name);
+            match self
+                .rule
+                .field_transforms
+                .iter()
+                .find(|t| t.field_name == field_name)
+                .map(|t| &t.transform_type)
+            {
+                Some(TransformType::Conditional(condition, func)) if condition.matches_f64(value) => {
+                    let _ = write!(self.writer, "{}={}", display_name, func(&value.to_string()));
+                    return;
+                }
+                Some(TransformType::Redact { matcher, keep_last }) => {
+                    if let Some(redacted) = matcher.redact(&value.to_string(), *keep_last) {
+                        let _ = write!(self.writer, "{}={}", display_name, redacted);
+                        return;
+                    }
+                }
+                Some(TransformType::RedactMode(mode)) => {
+                    if let Some(redacted) = mode.apply(&value.to_string()) {
+                        let _ = write!(self.writer, "{}={}", display_name, redacted);
+                    }
+                    return;
+                }
+                Some(TransformType::Typed(func)) => {
+                    let rendered = func(TransformValue::F64(value)).render();
+                    let _ = write!(self.writer, "{}={}", display_name, rendered);
+                    return;
+                }
+                _ => {}
+            }
             let _ = write!(self.writer, "{}={}", display_name, value);
         }
     }
@@ -364,15 +1756,45 @@ impl Visit for TransformingVisitor<'_> {
             .rule
             .hidden_fields
             .iter()
-            .any(|&hidden| hidden == field_name)
+            .any(|hidden| hidden == field_name)
         {
             let display_name = self
                 .rule
                 .field_renames
                 .iter()
-                .find(|(from, _)| *from == field_name)
-                .map(|(_, to)| *to)
+                .find(|(from, _)| from == field_name)
+                .map(|(_, to)| to.as_str())
                 .unwrap_or(field_name);
+            match self
+                .rule
+                .field_transforms
+                .iter()
+                .find(|t| t.field_name == field_name)
+                .map(|t| &t.transform_type)
+            {
+                Some(TransformType::Conditional(condition, func)) if condition.matches_bool(value) => {
+                    let _ = write!(self.writer, "{}={}", display_name, func(&value.to_string()));
+                    return;
+                }
+                Some(TransformType::Redact { matcher, keep_last }) => {
+                    if let Some(redacted) = matcher.redact(&value.to_string(), *keep_last) {
+                        let _ = write!(self.writer, "{}={}", display_name, redacted);
+                        return;
+                    }
+                }
+                Some(TransformType::RedactMode(mode)) => {
+                    if let Some(redacted) = mode.apply(&value.to_string()) {
+                        let _ = write!(self.writer, "{}={}", display_name, redacted);
+                    }
+                    return;
+                }
+                Some(TransformType::Typed(func)) => {
+                    let rendered = func(TransformValue::Bool(value)).render();
+                    let _ = write!(self.writer, "{}={}", display_name, rendered);
+                    return;
+                }
+                _ => {}
+            }
             let _ = write!(self.writer, "{}={}", display_name, value);
         }
     }
@@ -391,22 +1813,15 @@ where
     S: Subscriber + for<'a> LookupSpan<'a>,
 {
     fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
-        // Check if any rule matches this span's target
-        let target = attrs.metadata().target();
-
-        if let Some(rule) = self
-            .transforms
-            .target_rules
-            .iter()
-            .find(|rule| target.contains(rule.target_pattern))
-        {
+        // Check if any rule matches this span's callsite
+        if let Some(rule) = merge_matching_rules(&self.transforms.target_rules, attrs.metadata()) {
             // Apply transformations to this span's fields
             if let Some(span) = ctx.span(id) {
                 let mut extensions = span.extensions_mut();
 
                 // Create a new FormattedFields with transformed content
                 let mut fields = FormattedFields::<TransformConfig>::new(String::new());
-                let mut visitor = TransformingVisitor::new(fields.as_writer(), rule);
+                let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
                 attrs.record(&mut visitor);
 
                 // Store the transformed fields
@@ -417,14 +1832,7 @@ where
 
     fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
         if let Some(span) = ctx.span(id) {
-            let target = span.metadata().target();
-
-            if let Some(rule) = self
-                .transforms
-                .target_rules
-                .iter()
-                .find(|rule| target.contains(rule.target_pattern))
-            {
+            if let Some(rule) = merge_matching_rules(&self.transforms.target_rules, span.metadata()) {
                 let mut extensions = span.extensions_mut();
 
                 if let Some(fields) = extensions.get_mut::<FormattedFields<TransformConfig>>() {
@@ -432,12 +1840,12 @@ where
                     if !fields.fields.is_empty() {
                         fields.fields.push(' ');
                     }
-                    let mut visitor = TransformingVisitor::new(fields.as_writer(), rule);
+                    let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
                     values.record(&mut visitor);
                 } else {
                     // Create new transformed fields
                     let mut fields = FormattedFields::<TransformConfig>::new(String::new());
-                    let mut visitor = TransformingVisitor::new(fields.as_writer(), rule);
+                    let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
                     values.record(&mut visitor);
                     extensions.insert(fields);
                 }
@@ -446,209 +1854,1671 @@ where
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{layer::SubscriberExt, registry::Registry};
-    use tracing::{span, Level};
+/// Interior-mutable rule storage for a [`FieldTransformLayer`] built with
+/// [`FieldTransformLayer::new_with_handle`].
+#[derive(Debug, Clone)]
+pub struct ReloadableConfig(Arc<RwLock<TransformConfig>>);
 
-    #[test]
-    fn test_zero_cost_when_no_transforms() {
-        // Verify that the layer has zero cost when no transformations are configured
-        let layer = FieldTransformLayer::new();
+/// A handle returned alongside a reloadable [`FieldTransformLayer`] that lets its rules be
+/// replaced or edited at runtime.
+///
+/// Cloning a `Handle` is cheap; all clones control the same underlying rule set.
+#[derive(Debug, Clone)]
+pub struct Handle(Arc<RwLock<TransformConfig>>);
+
+impl Handle {
+    /// Atomically replace the entire rule set.
+    pub fn reload(&self, new_rules: TransformConfig) {
+        *self.0.write().unwrap() = new_rules;
+    }
 
-        // This should compile and have no runtime overhead
-        let subscriber = Registry::default().with(layer);
+    /// Edit the rule set in place under a write lock.
+    pub fn modify(&self, f: impl FnOnce(&mut TransformConfig)) {
+        f(&mut self.0.write().unwrap());
+    }
 
-        // Basic smoke test - ensure it doesn't panic
+    /// Add a single rule for `target_pattern`, built the same way as
+    /// [`FieldTransformLayer::with_target_transform`]. Takes effect for spans and events
+    /// recorded after this call returns.
+    pub fn add_rule<F>(&self, target_pattern: impl Into<String>, builder: F)
+    where
+        F: FnOnce(TargetRuleBuilder) -> TargetRuleBuilder,
+    {
+        self.modify(|config| {
+            config.add_target_transform(target_pattern, builder);
+        });
+    }
+
+    /// Add a rule gated by a custom predicate, built the same way as
+    /// [`FieldTransformLayer::with_filter_transform`]. Takes effect for spans and events
+    /// recorded after this call returns.
+    pub fn add_filter_rule<F, B>(&self, filter: F, builder: B)
+    where
+        F: Fn(&Metadata<'_>) -> bool + Send + Sync + 'static,
+        B: FnOnce(TargetRuleBuilder) -> TargetRuleBuilder,
+    {
+        self.modify(|config| {
+            config.add_filter_transform(filter, builder);
+        });
+    }
+
+    /// Remove every rule for the given target pattern.
+    pub fn remove_target(&self, target_pattern: &str) {
+        self.modify(|config| {
+            config.remove_target(target_pattern);
+        });
+    }
+
+    /// Replace the whole rule set with `rules`, discarding any existing rules.
+    pub fn replace_all(&self, rules: Vec<TargetRule>) {
+        self.reload(TransformConfig {
+            target_rules: rules,
+        });
+    }
+}
+
+impl<S> Layer<S> for FieldTransformLayer<ReloadableConfig>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let rules = self.transforms.0.read().unwrap();
+
+        if let Some(rule) = merge_matching_rules(&rules.target_rules, attrs.metadata()) {
+            if let Some(span) = ctx.span(id) {
+                let mut extensions = span.extensions_mut();
+                let mut fields = FormattedFields::<TransformConfig>::new(String::new());
+                let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
+                attrs.record(&mut visitor);
+                extensions.insert(fields);
+            }
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let rules = self.transforms.0.read().unwrap();
+
+            if let Some(rule) = merge_matching_rules(&rules.target_rules, span.metadata()) {
+                let mut extensions = span.extensions_mut();
+
+                if let Some(fields) = extensions.get_mut::<FormattedFields<TransformConfig>>() {
+                    if !fields.fields.is_empty() {
+                        fields.fields.push(' ');
+                    }
+                    let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
+                    values.record(&mut visitor);
+                } else {
+                    let mut fields = FormattedFields::<TransformConfig>::new(String::new());
+                    let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
+                    values.record(&mut visitor);
+                    extensions.insert(fields);
+                }
+            }
+        }
+    }
+}
+
+/// A [`FormatFields`] adapter that applies [`FieldTransformLayer`] rules directly in the fmt
+/// field-formatting path, rather than in a span extension a formatter may never read.
+///
+/// `FieldTransformLayer` itself only rewrites span attributes, into `FormattedFields<TransformConfig>`
+/// — a type the fmt layer's actual field formatter never looks up. Configuring this as that
+/// formatter instead (via `.fmt_fields(...)`) resolves the matching rule at the point
+/// `fmt::layer()` calls into it for *both* span attributes (`on_new_span`/`on_record`) and
+/// event fields, applying `hide_field`, `truncate_field`/`prefix_field`/`transform_field`,
+/// `redact_field`, and `rename_field` before the inner formatter ever sees the field. This
+/// works with [`JsonFields`](crate::fmt::format::JsonFields) just as well as the default text
+/// formatter: hidden fields are never recorded, transformed values are handed to the inner
+/// formatter as ordinary strings, and renamed fields are forwarded under a freshly built
+/// `Field` carrying the new name, so the inner formatter — including the JSON one — emits it
+/// as a real key, not just a relabeled value under the old one.
+///
+/// # Example
+///
+/// ```rust
+/// use better_tracing::layer::transform::{FieldTransformLayer, TransformFormatFields};
+///
+/// let (transform_layer, handle) = FieldTransformLayer::new_with_handle();
+///
+/// better_tracing::registry()
+///     .with(transform_layer)
+///     .with(
+///         better_tracing::fmt::layer()
+///             .fmt_fields(TransformFormatFields::with_handle(
+///                 better_tracing::fmt::format::DefaultFields::new(),
+///                 &handle,
+///             )),
+///     )
+///     .init();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformFormatFields<N> {
+    inner: N,
+    rules: Arc<RwLock<TransformConfig>>,
+}
+
+impl<N> TransformFormatFields<N> {
+    /// Wrap `inner`, resolving rules from a fixed, never-reloaded [`TransformConfig`].
+    pub fn new(inner: N, rules: TransformConfig) -> Self {
+        Self {
+            inner,
+            rules: Arc::new(RwLock::new(rules)),
+        }
+    }
+
+    /// Wrap `inner`, sharing the live rule set of a [`Handle`] returned by
+    /// [`FieldTransformLayer::new_with_handle`], so reloading the layer's rules also
+    /// retunes event-field transformation.
+    pub fn with_handle(inner: N, handle: &Handle) -> Self {
+        Self {
+            inner,
+            rules: handle.0.clone(),
+        }
+    }
+}
+
+impl<'a, N> FormatFields<'a> for TransformFormatFields<N>
+where
+    N: FormatFields<'a>,
+{
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'_>, fields: R) -> fmt::Result {
+        let rules = self.rules.read().unwrap();
+        let rule = merge_matching_rules(&rules.target_rules, fields.metadata());
+
+        match &rule {
+            Some(rule) => self.inner.format_fields(writer, RuleFilteredFields { fields, rule }),
+            None => self.inner.format_fields(writer, fields),
+        }
+    }
+
+    fn add_fields(
+        &self,
+        current: &'a mut FormattedFields<Self>,
+        fields: &Record<'_>,
+    ) -> fmt::Result {
+        if !current.fields.is_empty() {
+            current.fields.push(' ');
+        }
+        let writer = current.as_writer();
+        self.format_fields(writer, fields)
+    }
+}
+
+/// Wraps a [`RecordFields`] so that recording it runs each field through the matching
+/// [`TargetRule`]'s hide/transform logic before forwarding to the real visitor.
+struct RuleFilteredFields<'a, R> {
+    fields: R,
+    rule: &'a TargetRule,
+}
+
+impl<R: RecordFields> RecordFields for RuleFilteredFields<'_, R> {
+    fn record(&self, visitor: &mut dyn Visit) {
+        let has_context_transforms = self
+            .rule
+            .field_transforms
+            .iter()
+            .any(|t| matches!(t.transform_type, TransformType::WithContext(_)));
+
+        if !has_context_transforms {
+            let mut relay = HideAndTransformVisit {
+                rule: self.rule,
+                inner: visitor,
+            };
+            self.fields.record(&mut relay);
+            return;
+        }
+
+        // At least one field needs to see its siblings' values, so buffer every field first
+        // (applying all non-context transforms), then resolve the context transforms against
+        // that snapshot, then forward everything to `visitor` in original visit order.
+        let mut buffer = ContextBufferVisit::new(self.rule);
+        self.fields.record(&mut buffer);
+
+        let context_fields: Vec<(String, String)> = buffer
+            .buffered
+            .iter()
+            .map(|buffered| (buffered.out_field.name().to_string(), buffered.value.render()))
+            .collect();
+
+        for buffered in buffer.buffered {
+            let value = match buffered.context_transform {
+                Some(func) => {
+                    let context = FieldContext::new(&context_fields, buffered.out_field.name());
+                    TransformValue::Str(func(&buffered.value.render(), &context))
+                }
+                None => buffered.value,
+            };
+            value.record(visitor, &buffered.out_field);
+        }
+    }
+
+    fn metadata(&self) -> &tracing_core::Metadata<'_> {
+        self.fields.metadata()
+    }
+}
+
+struct HideAndTransformVisit<'a> {
+    rule: &'a TargetRule,
+    inner: &'a mut dyn Visit,
+}
+
+impl HideAndTransformVisit<'_> {
+    fn is_hidden(&self, field: &Field) -> bool {
+        is_hidden(self.rule, field)
+    }
+
+    fn transform_for(&self, field: &Field) -> Option<&FieldTransform> {
+        transform_for(self.rule, field)
+    }
+
+    /// Returns the `Field` a recorded value should be forwarded under: a freshly built one
+    /// named after the rule's rename target, or `field` itself when no rename applies.
+    fn resolve_field(&self, field: &Field) -> Field {
+        resolve_field(self.rule, field)
+    }
+}
+
+fn is_hidden(rule: &TargetRule, field: &Field) -> bool {
+    rule.hidden_fields.iter().any(|hidden| hidden == field.name())
+}
+
+fn transform_for<'a>(rule: &'a TargetRule, field: &Field) -> Option<&'a FieldTransform> {
+    rule.field_transforms
+        .iter()
+        .find(|t| t.field_name == field.name())
+}
+
+/// Returns the `Field` a recorded value should be forwarded under: a freshly built one named
+/// after the rule's rename target, or `field` itself when no rename applies.
+fn resolve_field(rule: &TargetRule, field: &Field) -> Field {
+    match rule.field_renames.iter().find(|(from, _)| from == field.name()) {
+        Some((_, to)) => rename_field_cached(field, to),
+        None => field.clone(),
+    }
+}
+
+/// Applies `transform` to `rendered`, returning `None` when a [`Match`]-gated or
+/// [`RedactMatcher`]-gated transform didn't match (so the caller should forward the original
+/// value untouched), or when `transform` is a [`TransformType::Typed`]/[`TransformType::WithContext`]
+/// transform, which callers handle separately. A [`TransformType::RedactMode`] of
+/// [`RedactMode::Drop`] is handled by callers *before* reaching here, since dropping a field
+/// entirely can't be expressed through this function's `Option<String>` result.
+///
+/// `is_debug` marks `rendered` as the output of `{:?}`, so a [`TransformType::Redact`] matches
+/// against the content inside the surrounding quotes rather than the quotes themselves.
+fn apply_simple(transform: &FieldTransform, rendered: &str, is_debug: bool) -> Option<String> {
+    match &transform.transform_type {
+        TransformType::Truncate(max_len) => Some(truncate_chars(rendered, *max_len)),
+        TransformType::Prefix(prefix) => Some(format!("{} {}", prefix, rendered)),
+        TransformType::Custom(func) => Some(func(rendered)),
+        TransformType::Conditional(condition, func) => {
+            condition.matches_rendered(rendered).then(|| func(rendered))
+        }
+        TransformType::Redact { matcher, keep_last } => {
+            if is_debug {
+                matcher.redact_debug(rendered, *keep_last)
+            } else {
+                matcher.redact(rendered, *keep_last)
+            }
+        }
+        // `RedactMode::Drop` is intercepted by callers before `apply_simple` is reached, so
+        // only `Mask`/`Hash` (which always produce a value) show up here.
+        TransformType::RedactMode(mode) => mode.apply(rendered),
+        TransformType::Typed(_) | TransformType::WithContext(_) => None,
+    }
+}
+
+/// Applies a [`TransformType::Typed`] transform to `original`, returning its typed result so
+/// the caller can forward it under its own kind. Returns `None` for every other transform
+/// type, so callers fall back to [`apply_simple`].
+fn apply_typed(transform: &FieldTransform, original: TransformValue) -> Option<TransformValue> {
+    match &transform.transform_type {
+        TransformType::Typed(func) => Some(func(original)),
+        _ => None,
+    }
+}
+
+/// One field captured by [`ContextBufferVisit`]'s first pass: the field (possibly renamed) it
+/// will ultimately be forwarded under, its value after any simple per-field transform has run,
+/// and whether it still needs a second-pass [`TransformType::WithContext`] transform applied.
+struct BufferedField {
+    out_field: Field,
+    value: TransformValue,
+    context_transform: Option<Arc<dyn Fn(&str, &FieldContext<'_>) -> String + Send + Sync>>,
+}
+
+/// First pass of context-aware field transformation: records every field's value, applying
+/// every transform *except* [`TransformType::WithContext`] ones, which are deferred to
+/// [`RuleFilteredFields::record`]'s second pass once every field's (non-context) value is
+/// known.
+struct ContextBufferVisit<'a> {
+    rule: &'a TargetRule,
+    buffered: Vec<BufferedField>,
+}
+
+impl<'a> ContextBufferVisit<'a> {
+    fn new(rule: &'a TargetRule) -> Self {
+        Self {
+            rule,
+            buffered: Vec::new(),
+        }
+    }
+
+    fn buffer(&mut self, field: &Field, value: TransformValue) {
+        if is_hidden(self.rule, field) {
+            return;
+        }
+
+        // Cross-field value-predicate redaction runs before any per-field transform, on every
+        // field regardless of whether it has one configured; see
+        // `TransformingVisitor::record_debug`.
+        let value = if self.rule.value_redactions.is_empty() {
+            value
+        } else {
+            let is_debug = matches!(value, TransformValue::Debug(_));
+            match apply_value_redactions_aware(self.rule, &value.render(), is_debug) {
+                Some(Some(redacted)) => TransformValue::Str(redacted),
+                Some(None) => return, // RedactMode::Drop
+                None => value,
+            }
+        };
+
+        let out_field = resolve_field(self.rule, field);
+        let Some(transform) = transform_for(self.rule, field) else {
+            self.buffered.push(BufferedField {
+                out_field,
+                value,
+                context_transform: None,
+            });
+            return;
+        };
+
+        if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+            return;
+        }
+
+        if let TransformType::WithContext(func) = &transform.transform_type {
+            self.buffered.push(BufferedField {
+                out_field,
+                value,
+                context_transform: Some(func.clone()),
+            });
+            return;
+        }
+
+        let is_debug = matches!(value, TransformValue::Debug(_));
+        let final_value = match apply_typed(transform, value.clone()) {
+            Some(typed) => typed,
+            None => apply_simple(transform, &value.render(), is_debug)
+                .map(TransformValue::Str)
+                .unwrap_or(value),
+        };
+        self.buffered.push(BufferedField {
+            out_field,
+            value: final_value,
+            context_transform: None,
+        });
+    }
+}
+
+impl Visit for ContextBufferVisit<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.buffer(field, TransformValue::Debug(format!("{:?}", value)));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.buffer(field, TransformValue::Str(value.to_string()));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.buffer(field, TransformValue::I64(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.buffer(field, TransformValue::U64(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.buffer(field, TransformValue::F64(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.buffer(field, TransformValue::Bool(value));
+    }
+}
+
+impl Visit for HideAndTransformVisit<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if self.is_hidden(field) {
+            return;
+        }
+        let out_field = self.resolve_field(field);
+
+        // Cross-field value-predicate redaction runs before any per-field transform; see
+        // `TransformingVisitor::record_debug`.
+        let redacted = if self.rule.value_redactions.is_empty() {
+            None
+        } else {
+            match apply_value_redactions_aware(self.rule, &format!("{:?}", value), true) {
+                Some(Some(redacted)) => Some(redacted),
+                Some(None) => return, // RedactMode::Drop
+                None => None,
+            }
+        };
+
+        if let Some(transform) = self.transform_for(field) {
+            if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+                return;
+            }
+            let was_redacted = redacted.is_some();
+            let rendered = redacted.unwrap_or_else(|| format!("{:?}", value));
+            if let Some(typed) = apply_typed(transform, TransformValue::Debug(rendered.clone())) {
+                typed.record(self.inner, &out_field);
+                return;
+            }
+            if let Some(rewritten) = apply_simple(transform, &rendered, true) {
+                self.inner.record_str(&out_field, &rewritten);
+                return;
+            }
+            if was_redacted {
+                self.inner.record_str(&out_field, &rendered);
+            } else {
+                self.inner.record_debug(&out_field, value);
+            }
+        } else if let Some(redacted) = redacted {
+            self.inner.record_str(&out_field, &redacted);
+        } else {
+            self.inner.record_debug(&out_field, value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if self.is_hidden(field) {
+            return;
+        }
+        let out_field = self.resolve_field(field);
+
+        // Cross-field value-predicate redaction runs first; see `record_debug` above.
+        let redacted = if self.rule.value_redactions.is_empty() {
+            None
+        } else {
+            match apply_value_redactions(self.rule, value) {
+                Some(Some(redacted)) => Some(redacted),
+                Some(None) => return, // RedactMode::Drop
+                None => None,
+            }
+        };
+
+        if let Some(transform) = self.transform_for(field) {
+            if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+                return;
+            }
+            let value_ref: &str = redacted.as_deref().unwrap_or(value);
+            if let Some(typed) = apply_typed(transform, TransformValue::Str(value_ref.to_string())) {
+                typed.record(self.inner, &out_field);
+                return;
+            }
+            if let Some(rewritten) = apply_simple(transform, value_ref, false) {
+                self.inner.record_str(&out_field, &rewritten);
+                return;
+            }
+            self.inner.record_str(&out_field, value_ref);
+        } else if let Some(redacted) = redacted {
+            self.inner.record_str(&out_field, &redacted);
+        } else {
+            self.inner.record_str(&out_field, value);
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        if self.is_hidden(field) {
+            return;
+        }
+        let out_field = self.resolve_field(field);
+        if let Some(transform) = self.transform_for(field) {
+            if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+                return;
+            }
+            if let Some(typed) = apply_typed(transform, TransformValue::I64(value)) {
+                typed.record(self.inner, &out_field);
+                return;
+            }
+            if let Some(rewritten) = apply_simple(transform, &value.to_string(), false) {
+                self.inner.record_str(&out_field, &rewritten);
+                return;
+            }
+        }
+        self.inner.record_i64(&out_field, value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if self.is_hidden(field) {
+            return;
+        }
+        let out_field = self.resolve_field(field);
+        if let Some(transform) = self.transform_for(field) {
+            if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+                return;
+            }
+            if let Some(typed) = apply_typed(transform, TransformValue::U64(value)) {
+                typed.record(self.inner, &out_field);
+                return;
+            }
+            if let Some(rewritten) = apply_simple(transform, &value.to_string(), false) {
+                self.inner.record_str(&out_field, &rewritten);
+                return;
+            }
+        }
+        self.inner.record_u64(&out_field, value);
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if self.is_hidden(field) {
+            return;
+        }
+        let out_field = self.resolve_field(field);
+        if let Some(transform) = self.transform_for(field) {
+            if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+                return;
+            }
+            if let Some(typed) = apply_typed(transform, TransformValue::F64(value)) {
+                typed.record(self.inner, &out_field);
+                return;
+            }
+            if let Some(rewritten) = apply_simple(transform, &value.to_string(), false) {
+                self.inner.record_str(&out_field, &rewritten);
+                return;
+            }
+        }
+        self.inner.record_f64(&out_field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if self.is_hidden(field) {
+            return;
+        }
+        let out_field = self.resolve_field(field);
+        if let Some(transform) = self.transform_for(field) {
+            if matches!(transform.transform_type, TransformType::RedactMode(RedactMode::Drop)) {
+                return;
+            }
+            if let Some(typed) = apply_typed(transform, TransformValue::Bool(value)) {
+                typed.record(self.inner, &out_field);
+                return;
+            }
+            if let Some(rewritten) = apply_simple(transform, &value.to_string(), false) {
+                self.inner.record_str(&out_field, &rewritten);
+                return;
+            }
+        }
+        self.inner.record_bool(&out_field, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layer::SubscriberExt, registry::Registry};
+    use std::sync::Mutex;
+    use tracing::{span, Level};
+
+    #[test]
+    fn test_zero_cost_when_no_transforms() {
+        // Verify that the layer has zero cost when no transformations are configured
+        let layer = FieldTransformLayer::new();
+
+        // This should compile and have no runtime overhead
+        let subscriber = Registry::default().with(layer);
+
+        // Basic smoke test - ensure it doesn't panic
+        tracing::subscriber::with_default(subscriber, || {
+            let span = span!(Level::INFO, "test_span", field1 = "value1");
+            let _guard = span.enter();
+        });
+    }
+
+    #[test]
+    fn test_layer_creation_and_configuration() {
+        // Test that the builder pattern works correctly
+        let layer = FieldTransformLayer::new()
+            .with_target_transform("kube", |builder| {
+                builder
+                    .rename_field("resource_name", "k8s_resource")
+                    .hide_field("internal_token")
+                    .truncate_field("uid", 8)
+                    .prefix_field("status", "üéØ")
+                    .transform_field("phase", |value| match value {
+                        "\"Running\"" => "‚úÖ Running".to_string(),
+                        "\"Failed\"" => "‚ùå Failed".to_string(),
+                        other => other.to_string(),
+                    })
+            })
+            .with_target_transform("http", |builder| {
+                builder
+                    .rename_field("method", "http_method")
+                    .truncate_field("url", 50)
+            });
+
+        // Verify the configuration was built correctly
+        assert_eq!(layer.transforms.target_rules.len(), 2);
+
+        let kube_rule = &layer.transforms.target_rules[0];
+        assert_eq!(kube_rule.target_pattern, "kube");
+        assert_eq!(kube_rule.field_renames.len(), 1);
+        assert_eq!(
+            kube_rule.field_renames[0],
+            ("resource_name", "k8s_resource")
+        );
+        assert_eq!(kube_rule.hidden_fields.len(), 1);
+        assert_eq!(kube_rule.hidden_fields[0], "internal_token");
+        assert_eq!(kube_rule.field_transforms.len(), 3);
+
+        let http_rule = &layer.transforms.target_rules[1];
+        assert_eq!(http_rule.target_pattern, "http");
+        assert_eq!(http_rule.field_renames.len(), 1);
+        assert_eq!(http_rule.field_renames[0], ("method", "http_method"));
+    }
+
+    #[test]
+    fn test_target_rule_builder() {
+        // Test the builder pattern for target rules
+        let builder = TargetRuleBuilder::new("test_target");
+        let rule = builder
+            .rename_field("old", "new")
+            .hide_field("secret")
+            .truncate_field("long", 10)
+            .prefix_field("status", "üéØ")
+            .transform_field("custom", |v| v.to_uppercase())
+            .build();
+
+        assert_eq!(rule.target_pattern, "test_target");
+        assert_eq!(rule.field_renames.len(), 1);
+        assert_eq!(rule.field_renames[0], ("old", "new"));
+        assert_eq!(rule.hidden_fields.len(), 1);
+        assert_eq!(rule.hidden_fields[0], "secret");
+        assert_eq!(rule.field_transforms.len(), 3);
+
+        // Test transform types
+        assert_eq!(rule.field_transforms[0].field_name, "long");
+        assert_eq!(rule.field_transforms[1].field_name, "status");
+        assert_eq!(rule.field_transforms[2].field_name, "custom");
+
+        match &rule.field_transforms[0].transform_type {
+            TransformType::Truncate(n) => assert_eq!(*n, 10),
+            _ => panic!("Expected Truncate transform"),
+        }
+
+        match &rule.field_transforms[1].transform_type {
+            TransformType::Prefix(p) => assert_eq!(*p, "üéØ"),
+            _ => panic!("Expected Prefix transform"),
+        }
+
+        match &rule.field_transforms[2].transform_type {
+            TransformType::Custom(_) => {} // Can't test function equality
+            _ => panic!("Expected Custom transform"),
+        }
+    }
+
+    #[test]
+    fn test_transform_types() {
+        // Test truncation logic
+        let value = "this_is_a_very_long_string";
+        let truncated = if value.len() > 10 {
+            format!("{}...", &value[..10])
+        } else {
+            value.to_string()
+        };
+        assert_eq!(truncated, "this_is_a_...");
+
+        // Test prefix logic
+        let prefixed = format!("üéØ {}", "test_value");
+        assert_eq!(prefixed, "üéØ test_value");
+
+        // Test custom transform
+        let custom_transform = |value: &str| match value {
+            "running" => "‚úÖ Running".to_string(),
+            "failed" => "‚ùå Failed".to_string(),
+            other => other.to_string(),
+        };
+        assert_eq!(custom_transform("running"), "‚úÖ Running");
+        assert_eq!(custom_transform("failed"), "‚ùå Failed");
+        assert_eq!(custom_transform("other"), "other");
+    }
+
+    #[test]
+    fn test_integration_with_registry() {
+        // Test that the layer properly integrates with the registry
+        let layer = FieldTransformLayer::new().with_target_transform("test_target", |builder| {
+            builder
+                .rename_field("field1", "renamed_field1")
+                .hide_field("secret")
+        });
+
+        let subscriber = Registry::default().with(layer);
+
+        // This should not panic and should work end-to-end
         tracing::subscriber::with_default(subscriber, || {
-            let span = span!(Level::INFO, "test_span", field1 = "value1");
+            let span = tracing::span!(
+                target: "test_target",
+                Level::INFO,
+                "test_span",
+                field1 = "value1",
+                secret = "hidden_value",
+                visible = "visible_value"
+            );
+            let _guard = span.enter();
+
+            // Test recording additional fields
+            span.record("field2", &"value2");
+        });
+    }
+
+    #[test]
+    fn test_multiple_layer_composition() {
+        // Test that transform layers can be composed with other layers
+        let transform_layer = FieldTransformLayer::new().with_target_transform("app", |builder| {
+            builder
+                .rename_field("user_id", "uid")
+                .hide_field("password")
+        });
+
+        let fmt_layer = crate::fmt::layer().with_target(true).with_level(true);
+
+        let subscriber = Registry::default().with(transform_layer).with(fmt_layer);
+
+        // Should compose properly without panic
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::span!(
+                target: "app::auth",
+                Level::INFO,
+                "login",
+                user_id = 12345,
+                password = "secret123",
+                method = "oauth"
+            );
+            let _guard = span.enter();
+        });
+    }
+
+    #[test]
+    fn test_no_allocation_when_no_match() {
+        // Test that no work is done when target doesn't match
+        let layer = FieldTransformLayer::new()
+            .with_target_transform("specific_target", |builder| {
+                builder.rename_field("field", "renamed")
+            });
+
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            // This span should not trigger any transformations
+            let span = tracing::span!(
+                target: "different_target",
+                Level::INFO,
+                "test_span",
+                field = "value"
+            );
+            let _guard = span.enter();
+        });
+    }
+
+    #[test]
+    fn test_transform_field_if_only_fires_on_match() {
+        let layer = FieldTransformLayer::new().with_target_transform("http", |builder| {
+            builder.transform_field_if("status_code", Match::I64(500), |v| format!("❌ {}", v))
+        });
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let matched = tracing::span!(
+                target: "http",
+                Level::INFO,
+                "request",
+                status_code = 500i64
+            );
+            let unmatched = tracing::span!(
+                target: "http",
+                Level::INFO,
+                "request",
+                status_code = 200i64
+            );
+            let _g1 = matched.enter();
+            let _g2 = unmatched.enter();
+        });
+    }
+
+    #[test]
+    fn test_with_reload_is_equivalent_to_new_with_handle() {
+        let (layer, handle) = FieldTransformLayer::new().with_reload();
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            handle.modify(|config| {
+                config.add_target_transform("incident", |builder| builder.hide_field("secret"));
+            });
+
+            let span = tracing::span!(target: "incident", Level::INFO, "noisy", secret = "abc123");
+            let _g = span.enter();
+        });
+    }
+
+    #[test]
+    fn test_reload_handle_modifies_rules_at_runtime() {
+        let (layer, handle) = FieldTransformLayer::new_with_handle();
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            // No rules yet: this span should pass through untouched.
+            let span = tracing::span!(target: "incident", Level::INFO, "noisy", secret = "abc123");
+            let _g = span.enter();
+            drop(_g);
+
+            // An ops endpoint tightens redaction for this target mid-process.
+            handle.modify(|config| {
+                config.add_target_transform("incident", |builder| builder.hide_field("secret"));
+            });
+
+            let span = tracing::span!(target: "incident", Level::INFO, "noisy", secret = "abc123");
+            let _g = span.enter();
+        });
+    }
+
+    #[test]
+    fn test_reload_handle_replaces_rules() {
+        let (layer, handle) = FieldTransformLayer::new_with_handle();
+        let mut config = TransformConfig::new();
+        config.add_target_transform("incident", |builder| builder.hide_field("secret"));
+        handle.reload(config);
+
+        let subscriber = Registry::default().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::span!(target: "incident", Level::INFO, "noisy", secret = "abc123");
+            let _g = span.enter();
+        });
+    }
+
+    // Captures formatted output so tests can assert on what the fmt layer actually wrote,
+    // rather than just that recording a transformed event didn't panic.
+    #[derive(Clone, Default)]
+    struct CapturingWriter {
+        output: Arc<Mutex<Vec<u8>>>,
+    }
+
+    impl CapturingWriter {
+        fn contents(&self) -> String {
+            String::from_utf8(self.output.lock().unwrap().clone()).expect("output should be utf8")
+        }
+    }
+
+    impl std::io::Write for CapturingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.output.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl crate::fmt::MakeWriter<'_> for CapturingWriter {
+        type Writer = Self;
+
+        fn make_writer(&self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_transform_format_fields_hides_and_transforms_event_fields() {
+        let (transform_layer, handle) = FieldTransformLayer::new_with_handle();
+        handle.modify(|config| {
+            config.add_target_transform("http", |builder| {
+                builder
+                    .hide_field("token")
+                    .truncate_field("url", 5)
+            });
+        });
+
+        let writer = CapturingWriter::default();
+        let fmt_layer = crate::fmt::layer()
+            .with_writer(writer.clone())
+            .with_ansi(false)
+            .fmt_fields(TransformFormatFields::with_handle(
+                crate::fmt::format::DefaultFields::new(),
+                &handle,
+            ));
+
+        let subscriber = Registry::default().with(transform_layer).with(fmt_layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: "http", token = "secret", url = "https://example.com", "request");
+        });
+
+        let output = writer.contents();
+        assert!(
+            !output.contains("secret"),
+            "hidden `token` field leaked into event output: {output}"
+        );
+        assert!(
+            output.contains("url=\"https...\""),
+            "truncated `url` field should still appear, just shortened: {output}"
+        );
+    }
+
+    #[test]
+    fn test_transform_format_fields_hides_and_renames_span_fields_via_scope() {
+        // A minimal custom formatter that walks `ctx.scope()` the way a real hierarchy
+        // renderer would, reading back each span's `FormattedFields<N>` directly, without
+        // going through the default `Format<L, T>` event formatter at all.
+        struct ScopeFormatter;
+        impl<S, N> crate::fmt::format::FormatEvent<S, N> for ScopeFormatter
+        where
+            S: Subscriber + for<'a> crate::registry::LookupSpan<'a>,
+            N: for<'a> FormatFields<'a> + 'static,
+        {
+            fn format_event(
+                &self,
+                ctx: &crate::fmt::FmtContext<'_, S, N>,
+                mut writer: Writer<'_>,
+                event: &tracing_core::Event<'_>,
+            ) -> fmt::Result {
+                if let Some(scope) = ctx.event_scope() {
+                    for span in scope.from_root() {
+                        write!(writer, "{}", span.name())?;
+                        let ext = span.extensions();
+                        if let Some(fields) = ext.get::<FormattedFields<N>>() {
+                            if !fields.fields.is_empty() {
+                                write!(writer, "{{{}}}", fields.fields)?;
+                            }
+                        }
+                        write!(writer, " ")?;
+                    }
+                }
+                writeln!(writer, "{}", event.metadata().name())
+            }
+        }
+
+        let (transform_layer, handle) = FieldTransformLayer::new_with_handle();
+        handle.modify(|config| {
+            config.add_target_transform("db", |builder| {
+                builder
+                    .hide_field("connection_id")
+                    .rename_field("task_id", "task")
+            });
+        });
+
+        let writer = CapturingWriter::default();
+        let fmt_layer = crate::fmt::layer()
+            .with_writer(writer.clone())
+            .with_ansi(false)
+            .event_format(ScopeFormatter)
+            .fmt_fields(TransformFormatFields::with_handle(
+                crate::fmt::format::DefaultFields::new(),
+                &handle,
+            ));
+
+        let subscriber = Registry::default().with(transform_layer).with(fmt_layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::span!(
+                target: "db",
+                Level::INFO,
+                "query",
+                connection_id = "conn-abc123",
+                task_id = 456
+            );
+            let _guard = span.enter();
+            tracing::info!("done");
+        });
+
+        let output = writer.contents();
+        assert!(
+            !output.contains("conn-abc123"),
+            "hidden `connection_id` field leaked into span output read via ctx.scope(): {output}"
+        );
+        assert!(
+            output.contains("task=456"),
+            "renamed `task_id` field should appear as `task` in span output: {output}"
+        );
+    }
+
+    #[test]
+    fn test_rename_field_builds_field_with_new_name_on_same_callsite() {
+        let span = tracing::info_span!("rename_test", original = "value");
+        let field = span
+            .metadata()
+            .expect("span should have metadata")
+            .fields()
+            .field("original")
+            .expect("span has an `original` field");
+
+        let renamed = rename_field(&field, "renamed");
+        assert_eq!(renamed.name(), "renamed");
+        assert_eq!(renamed.callsite(), field.callsite());
+    }
+
+    #[test]
+    fn test_transform_format_fields_renames_span_and_event_fields() {
+        let (transform_layer, handle) = FieldTransformLayer::new_with_handle();
+        handle.modify(|config| {
+            config.add_target_transform("db", |builder| builder.rename_field("conn_id", "conn"));
+        });
+
+        let fmt_layer = crate::fmt::layer()
+            .with_writer(std::io::sink)
+            .fmt_fields(TransformFormatFields::with_handle(
+                crate::fmt::format::DefaultFields::new(),
+                &handle,
+            ));
+
+        let subscriber = Registry::default().with(transform_layer).with(fmt_layer);
+
+        // Should not panic; the renamed field is forwarded under its new name both for the
+        // span's own attributes and for a plain event's fields.
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!(target: "db", "query", conn_id = "abc-123");
             let _guard = span.enter();
+            tracing::info!(target: "db", conn_id = "abc-123", "querying");
         });
     }
 
     #[test]
-    fn test_layer_creation_and_configuration() {
-        // Test that the builder pattern works correctly
-        let layer = FieldTransformLayer::new()
-            .with_target_transform("kube", |builder| {
-                builder
-                    .rename_field("resource_name", "k8s_resource")
-                    .hide_field("internal_token")
-                    .truncate_field("uid", 8)
-                    .prefix_field("status", "üéØ")
-                    .transform_field("phase", |value| match value {
-                        "\"Running\"" => "‚úÖ Running".to_string(),
-                        "\"Failed\"" => "‚ùå Failed".to_string(),
-                        other => other.to_string(),
-                    })
-            })
-            .with_target_transform("http", |builder| {
-                builder
-                    .rename_field("method", "http_method")
-                    .truncate_field("url", 50)
-            });
+    fn test_from_str_parses_directives() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let layer =
+            FieldTransformLayer::from_str("kube=hide(token);truncate(uid,8);rename(ns,namespace)", &named)
+                .expect("directive string should parse");
+
+        assert_eq!(layer.transforms.target_rules.len(), 1);
+        let rule = &layer.transforms.target_rules[0];
+        assert_eq!(rule.target_pattern, "kube");
+        assert_eq!(rule.hidden_fields, vec!["token"]);
+        assert_eq!(rule.field_renames, vec![("ns", "namespace")]);
+        match &rule.field_transforms[0].transform_type {
+            TransformType::Truncate(8) => {}
+            other => panic!("expected Truncate(8), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_str_resolves_named_transform() {
+        let mut named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        named.insert("shout".to_string(), Arc::new(|v: &str| v.to_uppercase()));
+
+        let layer = FieldTransformLayer::from_str("app=transform(status,shout)", &named)
+            .expect("directive string should parse");
+        let rule = &layer.transforms.target_rules[0];
+        match &rule.field_transforms[0].transform_type {
+            TransformType::Custom(f) => assert_eq!(f("ok"), "OK"),
+            other => panic!("expected Custom, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_str_parses_prefix_op() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let layer = FieldTransformLayer::from_str("kube=prefix(kind,📦)", &named)
+            .expect("directive string should parse");
+        let rule = &layer.transforms.target_rules[0];
+        match &rule.field_transforms[0].transform_type {
+            TransformType::Prefix(prefix) => assert_eq!(prefix, "📦"),
+            other => panic!("expected Prefix, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_str_rejects_unknown_op() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let err = FieldTransformLayer::from_str("kube=frobnicate(field)", &named).unwrap_err();
+        assert!(err.to_string().contains("unknown op"));
+    }
 
-        // Verify the configuration was built correctly
-        assert_eq!(layer.transforms.target_rules.len(), 2);
+    #[test]
+    fn test_from_str_rejects_bad_truncate_len() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let err = FieldTransformLayer::from_str("kube=truncate(uid,nope)", &named).unwrap_err();
+        assert!(err.to_string().contains("not a valid number"));
+    }
 
-        let kube_rule = &layer.transforms.target_rules[0];
-        assert_eq!(kube_rule.target_pattern, "kube");
-        assert_eq!(kube_rule.field_renames.len(), 1);
-        assert_eq!(
-            kube_rule.field_renames[0],
-            ("resource_name", "k8s_resource")
-        );
-        assert_eq!(kube_rule.hidden_fields.len(), 1);
-        assert_eq!(kube_rule.hidden_fields[0], "internal_token");
-        assert_eq!(kube_rule.field_transforms.len(), 3);
+    #[test]
+    fn test_from_str_error_position_points_at_failing_op() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        // The second directive's `truncate` op is the malformed one; its position should
+        // point at that op specifically, well past byte 0.
+        let directives = "kube=hide(token),containerd=truncate(id,nope)";
+        let err = FieldTransformLayer::from_str(directives, &named).unwrap_err();
+        let failing_op_start = directives.rfind("truncate").unwrap();
+        assert_eq!(err.position(), failing_op_start);
+    }
 
-        let http_rule = &layer.transforms.target_rules[1];
-        assert_eq!(http_rule.target_pattern, "http");
-        assert_eq!(http_rule.field_renames.len(), 1);
-        assert_eq!(http_rule.field_renames[0], ("method", "http_method"));
+    #[test]
+    fn test_from_env_missing_var_is_identity() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let layer = FieldTransformLayer::from_env(
+            "BETTER_TRACE_TRANSFORM_DOES_NOT_EXIST",
+            &named,
+        )
+        .expect("missing env var should not error");
+        assert!(layer.transforms.target_rules.is_empty());
     }
 
     #[test]
-    fn test_target_rule_builder() {
-        // Test the builder pattern for target rules
-        let builder = TargetRuleBuilder::new("test_target");
-        let rule = builder
-            .rename_field("old", "new")
-            .hide_field("secret")
-            .truncate_field("long", 10)
-            .prefix_field("status", "üéØ")
-            .transform_field("custom", |v| v.to_uppercase())
-            .build();
+    fn test_from_str_parses_grouped_directives() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let layer = FieldTransformLayer::from_str(
+            "kube{resource_name=>name,uid=trunc:8,resource_version=hide}",
+            &named,
+        )
+        .expect("directive string should parse");
+
+        assert_eq!(layer.transforms.target_rules.len(), 1);
+        let rule = &layer.transforms.target_rules[0];
+        assert_eq!(rule.target_pattern, "kube");
+        assert_eq!(rule.field_renames, vec![("resource_name", "name")]);
+        assert_eq!(rule.hidden_fields, vec!["resource_version"]);
+        match &rule.field_transforms[0].transform_type {
+            TransformType::Truncate(8) => {}
+            other => panic!("expected Truncate(8), got {:?}", other),
+        }
+    }
 
-        assert_eq!(rule.target_pattern, "test_target");
-        assert_eq!(rule.field_renames.len(), 1);
-        assert_eq!(rule.field_renames[0], ("old", "new"));
-        assert_eq!(rule.hidden_fields.len(), 1);
-        assert_eq!(rule.hidden_fields[0], "secret");
-        assert_eq!(rule.field_transforms.len(), 3);
+    #[test]
+    fn test_from_str_parses_multiple_groups() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let layer = FieldTransformLayer::from_str(
+            "kube{uid=hide};containerd{container_id=trunc:12,size_bytes=bytes}",
+            &named,
+        )
+        .expect("directive string should parse");
 
-        // Test transform types
-        assert_eq!(rule.field_transforms[0].field_name, "long");
-        assert_eq!(rule.field_transforms[1].field_name, "status");
-        assert_eq!(rule.field_transforms[2].field_name, "custom");
+        assert_eq!(layer.transforms.target_rules.len(), 2);
+        assert_eq!(layer.transforms.target_rules[0].target_pattern, "kube");
+        let containerd = &layer.transforms.target_rules[1];
+        assert_eq!(containerd.target_pattern, "containerd");
+        assert_eq!(containerd.field_transforms[1].field_name, "size_bytes");
+        match &containerd.field_transforms[1].transform_type {
+            TransformType::Custom(f) => assert_eq!(f("1536"), "1.5 KiB"),
+            other => panic!("expected Custom, got {:?}", other),
+        }
+    }
 
+    #[test]
+    fn test_from_str_parses_prefix_rule() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let layer = FieldTransformLayer::from_str("kube{kind=prefix:📦}", &named)
+            .expect("directive string should parse");
+        let rule = &layer.transforms.target_rules[0];
         match &rule.field_transforms[0].transform_type {
-            TransformType::Truncate(n) => assert_eq!(*n, 10),
-            _ => panic!("Expected Truncate transform"),
+            TransformType::Prefix(prefix) => assert_eq!(prefix, "📦"),
+            other => panic!("expected Prefix, got {:?}", other),
         }
+    }
 
-        match &rule.field_transforms[1].transform_type {
-            TransformType::Prefix(p) => assert_eq!(*p, "üéØ"),
-            _ => panic!("Expected Prefix transform"),
-        }
+    #[test]
+    fn test_from_str_rejects_unknown_grouped_rule() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let err = FieldTransformLayer::from_str("kube{uid=frobnicate}", &named).unwrap_err();
+        assert!(err.to_string().contains("unknown rule"));
+    }
 
-        match &rule.field_transforms[2].transform_type {
-            TransformType::Custom(_) => {} // Can't test function equality
-            _ => panic!("Expected Custom transform"),
-        }
+    #[test]
+    fn test_from_str_rejects_missing_braces() {
+        let named: HashMap<String, Arc<dyn Fn(&str) -> String + Send + Sync>> = HashMap::new();
+        let err = FieldTransformLayer::from_str("kube(uid=hide)", &named).unwrap_err();
+        assert!(err.to_string().contains("braces"));
     }
 
     #[test]
-    fn test_transform_types() {
-        // Test truncation logic
-        let value = "this_is_a_very_long_string";
-        let truncated = if value.len() > 10 {
-            format!("{}...", &value[..10])
-        } else {
-            value.to_string()
-        };
-        assert_eq!(truncated, "this_is_a_...");
+    fn test_format_bytes_renders_binary_units() {
+        assert_eq!(format_bytes("512"), "512 B");
+        assert_eq!(format_bytes("1536"), "1.5 KiB");
+        assert_eq!(format_bytes("1073741824"), "1.0 GiB");
+        // Not a number: passed through unchanged rather than panicking.
+        assert_eq!(format_bytes("not-a-number"), "not-a-number");
+    }
 
-        // Test prefix logic
-        let prefixed = format!("üéØ {}", "test_value");
-        assert_eq!(prefixed, "üéØ test_value");
+    #[test]
+    fn test_mask_keeps_last_n_chars_on_char_boundaries() {
+        assert_eq!(mask("1234567890", 4), "******7890");
+        // Fewer characters than `keep_last`: nothing to hide.
+        assert_eq!(mask("abc", 4), "abc");
+        // Masking must not split a multi-byte character in half.
+        assert_eq!(mask("pâsswörd", 2), "******rd");
+    }
 
-        // Test custom transform
-        let custom_transform = |value: &str| match value {
-            "running" => "‚úÖ Running".to_string(),
-            "failed" => "‚ùå Failed".to_string(),
-            other => other.to_string(),
-        };
-        assert_eq!(custom_transform("running"), "‚úÖ Running");
-        assert_eq!(custom_transform("failed"), "‚ùå Failed");
-        assert_eq!(custom_transform("other"), "other");
+    #[test]
+    fn test_truncate_chars_is_utf8_safe() {
+        // The previous byte-indexed slice would panic splitting this string mid-character.
+        assert_eq!(truncate_chars("café résumé", 5), "café ...");
+        assert_eq!(truncate_chars("short", 10), "short");
     }
 
     #[test]
-    fn test_integration_with_registry() {
-        // Test that the layer properly integrates with the registry
-        let layer = FieldTransformLayer::new().with_target_transform("test_target", |builder| {
+    fn test_redact_field_masks_matching_string_and_debug_values() {
+        let layer = FieldTransformLayer::new().with_target_transform("auth", |builder| {
             builder
-                .rename_field("field1", "renamed_field1")
-                .hide_field("secret")
+                .redact_field("password", RedactMatcher::Contains(String::new()))
+                .redact_field("status", RedactMatcher::Exact("Pending".to_string()))
         });
-
         let subscriber = Registry::default().with(layer);
 
-        // This should not panic and should work end-to-end
+        // Should not panic; the Redact arm of `TransformingVisitor` handles both
+        // `record_str` (password) and `record_debug` (status), where the latter must
+        // mask only the content inside the surrounding quotes.
         tracing::subscriber::with_default(subscriber, || {
             let span = tracing::span!(
-                target: "test_target",
+                target: "auth",
                 Level::INFO,
-                "test_span",
-                field1 = "value1",
-                secret = "hidden_value",
-                visible = "visible_value"
+                "login",
+                password = "hunter2",
+                status = ?"Pending"
             );
             let _guard = span.enter();
-
-            // Test recording additional fields
-            span.record("field2", &"value2");
         });
     }
 
     #[test]
-    fn test_multiple_layer_composition() {
-        // Test that transform layers can be composed with other layers
-        let transform_layer = FieldTransformLayer::new().with_target_transform("app", |builder| {
-            builder
-                .rename_field("user_id", "uid")
-                .hide_field("password")
-        });
+    fn test_redact_matching_keeps_last_n_visible_via_regex() {
+        let rule = TargetRuleBuilder::new("payments")
+            .redact_matching("card_number", regex::Regex::new(r"\d+").unwrap(), 4)
+            .build();
+        let mut fields = FormattedFields::<TransformConfig>::new(String::new());
+        let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
+
+        // Get a real `Field` handle bound to this span's callsite, the same way
+        // `on_new_span`/`on_record` do, rather than constructing one by hand.
+        let span = tracing::span!(Level::INFO, "card_span", card_number = "4111111111111111");
+        let field = span
+            .metadata()
+            .expect("span should have metadata")
+            .fields()
+            .field("card_number")
+            .expect("field should exist on this span's callsite");
+        visitor.record_str(&field, "4111111111111111");
+
+        assert_eq!(fields.fields, "card_number=************1111");
+    }
 
-        let fmt_layer = crate::fmt::layer().with_target(true).with_level(true);
+    #[test]
+    fn test_redact_field_with_mode_hash_is_stable_and_hides_original() {
+        let rule = TargetRuleBuilder::new("auth")
+            .redact_field_with_mode("token", RedactMode::Hash)
+            .build();
+        let mut fields = FormattedFields::<TransformConfig>::new(String::new());
+        let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
+
+        let span = tracing::span!(Level::INFO, "login", token = "super-secret");
+        let field = span
+            .metadata()
+            .expect("span should have metadata")
+            .fields()
+            .field("token")
+            .expect("field should exist on this span's callsite");
+        visitor.record_str(&field, "super-secret");
+
+        assert!(
+            !fields.fields.contains("super-secret"),
+            "hashed field should never contain the original value: {}",
+            fields.fields
+        );
+        // Hashing is deterministic, so recording the same value again yields the same digest.
+        let mut fields_again = FormattedFields::<TransformConfig>::new(String::new());
+        let mut visitor_again = TransformingVisitor::new(fields_again.as_writer(), &rule);
+        visitor_again.record_str(&field, "super-secret");
+        assert_eq!(fields.fields, fields_again.fields);
+    }
 
-        let subscriber = Registry::default().with(transform_layer).with(fmt_layer);
+    #[test]
+    fn test_redact_field_with_mode_drop_omits_field_entirely() {
+        let layer = FieldTransformLayer::new().with_target_transform("auth", |builder| {
+            builder.redact_field_with_mode("token", RedactMode::Drop)
+        });
+        let subscriber = Registry::default().with(layer);
 
-        // Should compose properly without panic
+        // Should not panic; `RedactMode::Drop` is handled as an early return rather than a
+        // rewritten value.
         tracing::subscriber::with_default(subscriber, || {
             let span = tracing::span!(
-                target: "app::auth",
+                target: "auth",
                 Level::INFO,
                 "login",
-                user_id = 12345,
-                password = "secret123",
-                method = "oauth"
+                token = "super-secret"
             );
             let _guard = span.enter();
         });
     }
 
     #[test]
-    fn test_no_allocation_when_no_match() {
-        // Test that no work is done when target doesn't match
-        let layer = FieldTransformLayer::new()
-            .with_target_transform("specific_target", |builder| {
-                builder.rename_field("field", "renamed")
+    fn test_redact_value_matching_catches_secret_regardless_of_field_name() {
+        let (transform_layer, handle) = FieldTransformLayer::new_with_handle();
+        handle.modify(|config| {
+            config.add_target_transform("http", |builder| {
+                builder.redact_value_matching(
+                    |value| value.starts_with("sk-"),
+                    RedactMode::Mask { keep_last: 0 },
+                )
             });
+        });
 
-        let subscriber = Registry::default().with(layer);
+        let writer = CapturingWriter::default();
+        let fmt_layer = crate::fmt::layer()
+            .with_writer(writer.clone())
+            .with_ansi(false)
+            .fmt_fields(TransformFormatFields::with_handle(
+                crate::fmt::format::DefaultFields::new(),
+                &handle,
+            ));
+
+        let subscriber = Registry::default().with(transform_layer).with(fmt_layer);
 
         tracing::subscriber::with_default(subscriber, || {
-            // This span should not trigger any transformations
-            let span = tracing::span!(
-                target: "different_target",
-                Level::INFO,
-                "test_span",
-                field = "value"
+            // `api_key` has no dedicated rule of its own; the cross-field predicate must
+            // still catch it purely by its value's shape.
+            tracing::info!(target: "http", api_key = "sk-abcdef123456", "calling out");
+        });
+
+        let output = writer.contents();
+        assert!(
+            !output.contains("sk-abcdef123456"),
+            "value matching the cross-field predicate should be redacted: {output}"
+        );
+    }
+
+    #[test]
+    fn test_redact_value_matching_runs_before_truncate() {
+        let rule = TargetRuleBuilder::new("http")
+            .redact_value_matching(|value| value.starts_with("sk-"), RedactMode::Hash)
+            .truncate_field("api_key", 4)
+            .build();
+        let mut fields = FormattedFields::<TransformConfig>::new(String::new());
+        let mut visitor = TransformingVisitor::new(fields.as_writer(), &rule);
+
+        let span = tracing::span!(Level::INFO, "call", api_key = "sk-abcdef123456");
+        let field = span
+            .metadata()
+            .expect("span should have metadata")
+            .fields()
+            .field("api_key")
+            .expect("field should exist on this span's callsite");
+        visitor.record_str(&field, "sk-abcdef123456");
+
+        // The hashed digest is 8 hex chars; `truncate_field` then shortens *that*, not the
+        // original secret, to 4 chars plus the `...` suffix `truncate_chars` appends.
+        assert!(
+            !fields.fields.contains("sk-abcdef123456"),
+            "redaction should have replaced the secret before truncation ran: {}",
+            fields.fields
+        );
+        assert!(
+            fields.fields.contains("..."),
+            "truncate_field should still have run on the redacted value: {}",
+            fields.fields
+        );
+    }
+
+    #[test]
+    fn test_target_matches_respects_path_boundaries() {
+        assert!(target_matches("http", "http"));
+        assert!(target_matches("http::client", "http"));
+        // A naive `contains` would wrongly match both of these.
+        assert!(!target_matches("http_client", "http"));
+        assert!(!target_matches("my_http", "http"));
+    }
+
+    #[test]
+    fn test_merge_matching_rules_lets_more_specific_pattern_win() {
+        let mut config = TransformConfig::new();
+        config.add_target_transform("http", |builder| {
+            builder.hide_field("noisy").truncate_field("url", 10)
+        });
+        config.add_target_transform("http::client", |builder| builder.truncate_field("url", 5));
+
+        let span = tracing::span!(target: "http::client", Level::INFO, "client_span");
+        let merged = merge_matching_rules(
+            &config.target_rules,
+            span.metadata().expect("span should have metadata"),
+        )
+        .expect("both rules should match http::client");
+
+        // A field only the general rule touches still applies...
+        assert_eq!(merged.hidden_fields, vec!["noisy"]);
+        // ...but for a field both rules touch, the more specific one wins.
+        let url_transform = merged
+            .field_transforms
+            .iter()
+            .find(|t| t.field_name == "url")
+            .expect("url should have a merged transform");
+        match url_transform.transform_type {
+            TransformType::Truncate(5) => {}
+            ref other => panic!("expected the more specific Truncate(5), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_transform_value_sees_original_kind() {
+        let rule = TargetRuleBuilder::new("sqlx")
+            .transform_value("duration_ms", |v| match v {
+                TransformValue::F64(ms) => TransformValue::F64(ms / 1000.0),
+                other => other,
+            })
+            .build();
+
+        struct CapturingVisit(Option<f64>);
+        impl Visit for CapturingVisit {
+            fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+            fn record_f64(&mut self, _: &Field, value: f64) {
+                self.0 = Some(value);
+            }
+        }
+
+        let span = tracing::span!(Level::INFO, "query", duration_ms = 2500.0);
+        let field = span
+            .metadata()
+            .expect("span should have metadata")
+            .fields()
+            .field("duration_ms")
+            .expect("field should exist on this span's callsite");
+
+        let mut captured = CapturingVisit(None);
+        let mut relay = HideAndTransformVisit {
+            rule: &rule,
+            inner: &mut captured,
+        };
+        relay.record_f64(&field, 2500.0);
+
+        // The closure saw an `f64` (not a stringified `"2500"`) and its result was
+        // forwarded as an `f64` too, rather than round-tripping through a string.
+        assert_eq!(captured.0, Some(2.5));
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_json_fields_hides_renames_and_transforms() {
+        let (transform_layer, handle) = FieldTransformLayer::new_with_handle();
+        handle.modify(|config| {
+            config.add_target_transform("http", |builder| {
+                builder
+                    .hide_field("token")
+                    .rename_field("method", "http_method")
+                    .truncate_field("url", 5)
+            });
+        });
+
+        let writer = CapturingWriter::default();
+        let fmt_layer = crate::fmt::layer()
+            .json()
+            .with_writer(writer.clone())
+            .fmt_fields(TransformFormatFields::with_handle(
+                crate::fmt::format::JsonFields::new(),
+                &handle,
+            ));
+
+        let subscriber = Registry::default().with(transform_layer).with(fmt_layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(
+                target: "http",
+                token = "secret",
+                method = "GET",
+                url = "https://example.com",
+                "request"
             );
-            let _guard = span.enter();
         });
+
+        let output = writer.contents();
+        let json: serde_json::Value =
+            serde_json::from_str(output.lines().next().expect("one line of JSON output"))
+                .expect("output should be a JSON object");
+        let fields = &json["fields"];
+        assert!(
+            fields.get("token").is_none(),
+            "hidden `token` field leaked into JSON output: {output}"
+        );
+        assert_eq!(fields["http_method"], "GET", "renamed key should carry the original value");
+        assert_eq!(
+            fields["url"],
+            serde_json::Value::String("https...".to_string()),
+            "truncated value should be a plain JSON string: {output}"
+        );
+    }
+
+    #[test]
+    fn test_transform_with_context_sees_sibling_fields() {
+        let (transform_layer, handle) = FieldTransformLayer::new_with_handle();
+        handle.modify(|config| {
+            config.add_target_transform("k8s", |builder| {
+                builder.transform_with_context("resource_name", |value, fields| {
+                    match fields.get("phase") {
+                        Some("Failed") => format!("!!{value}!!"),
+                        _ => value.to_string(),
+                    }
+                })
+            });
+        });
+
+        let writer = CapturingWriter::default();
+        let fmt_layer = crate::fmt::layer()
+            .json()
+            .with_writer(writer.clone())
+            .fmt_fields(TransformFormatFields::with_handle(
+                crate::fmt::format::JsonFields::new(),
+                &handle,
+            ));
+
+        let subscriber = Registry::default().with(transform_layer).with(fmt_layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: "k8s", resource_name = "pod-a", phase = "Failed", "status");
+            tracing::info!(target: "k8s", resource_name = "pod-b", phase = "Running", "status");
+        });
+
+        let output = writer.contents();
+        let mut lines = output.lines();
+        let failed: serde_json::Value = serde_json::from_str(lines.next().expect("first line"))
+            .expect("output should be a JSON object");
+        let running: serde_json::Value = serde_json::from_str(lines.next().expect("second line"))
+            .expect("output should be a JSON object");
+
+        assert_eq!(
+            failed["fields"]["resource_name"], "!!pod-a!!",
+            "context transform should see the sibling `phase` field: {output}"
+        );
+        assert_eq!(
+            running["fields"]["resource_name"], "pod-b",
+            "non-matching phase should leave the value untouched: {output}"
+        );
+    }
+
+    #[test]
+    fn test_parse_target_pattern_splits_field_scope_and_level() {
+        assert_eq!(
+            parse_target_pattern("reqwest::client"),
+            ("reqwest::client".to_string(), None, None)
+        );
+        assert_eq!(
+            parse_target_pattern("sqlx[rows_affected]"),
+            ("sqlx".to_string(), Some("rows_affected".to_string()), None)
+        );
+        assert_eq!(
+            parse_target_pattern("tokio::runtime[task_id]=info"),
+            (
+                "tokio::runtime".to_string(),
+                Some("task_id".to_string()),
+                Some(Level::INFO)
+            )
+        );
+    }
+
+    #[test]
+    fn test_merge_matching_rules_respects_level_threshold() {
+        let mut config = TransformConfig::new();
+        config.add_target_transform("reqwest", |builder| builder.hide_field("token"));
+        config.add_target_transform("reqwest::client=debug", |builder| {
+            builder.hide_field("body")
+        });
+
+        let info_event = tracing::span!(target: "reqwest::client", Level::INFO, "info_span");
+        let merged = merge_matching_rules(
+            &config.target_rules,
+            info_event.metadata().expect("span should have metadata"),
+        )
+        .expect("the untargeted rule should still match an INFO event");
+        assert_eq!(merged.hidden_fields, vec!["token"]);
+
+        let debug_event = tracing::span!(target: "reqwest::client", Level::DEBUG, "debug_span");
+        let merged = merge_matching_rules(
+            &config.target_rules,
+            debug_event.metadata().expect("span should have metadata"),
+        )
+        .expect("both rules should match a DEBUG event");
+        assert!(merged.hidden_fields.contains(&"token".to_string()));
+        assert!(merged.hidden_fields.contains(&"body".to_string()));
+    }
+
+    #[test]
+    fn test_merge_matching_rules_respects_field_scope() {
+        let mut config = TransformConfig::new();
+        config.add_target_transform("sqlx[rows_affected]", |builder| {
+            builder.truncate_field("rows_affected", 3)
+        });
+
+        let with_field =
+            tracing::span!(target: "sqlx", Level::INFO, "query", rows_affected = 42);
+        assert!(merge_matching_rules(
+            &config.target_rules,
+            with_field.metadata().expect("span should have metadata")
+        )
+        .is_some());
+
+        let without_field = tracing::span!(target: "sqlx", Level::INFO, "query", other = 1);
+        assert!(merge_matching_rules(
+            &config.target_rules,
+            without_field.metadata().expect("span should have metadata")
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_with_filter_transform_gates_on_predicate_not_target() {
+        let layer = FieldTransformLayer::new().with_filter_transform(
+            |meta| *meta.level() <= Level::WARN,
+            |builder| builder.hide_field("noisy"),
+        );
+
+        let warn_span = tracing::warn_span!(target: "anywhere", "query");
+        assert!(merge_matching_rules(
+            &layer.transforms.target_rules,
+            warn_span.metadata().expect("span should have metadata")
+        )
+        .is_some());
+
+        let info_span = tracing::info_span!(target: "anywhere", "query");
+        assert!(merge_matching_rules(
+            &layer.transforms.target_rules,
+            info_span.metadata().expect("span should have metadata")
+        )
+        .is_none());
     }
 }
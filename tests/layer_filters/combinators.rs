@@ -0,0 +1,71 @@
+use super::*;
+use better_tracing::filter::{filter_fn, FilterExt};
+use tracing::{level_filters::LevelFilter, Level};
+
+#[test]
+fn and_requires_both_filters() {
+    let (expect, handle) = layer::mock()
+        .event(expect::event().at_level(Level::WARN))
+        .event(expect::event().at_level(Level::ERROR))
+        .only()
+        .run_with_handle();
+
+    let is_loud = filter_fn(|meta| meta.target().starts_with("loud"));
+
+    let _subscriber = better_tracing::registry()
+        .with(expect.with_filter(LevelFilter::WARN.and(is_loud)))
+        .set_default();
+
+    tracing::trace!(target: "loud", "hello trace");
+    tracing::info!(target: "loud", "hello info");
+    tracing::warn!(target: "loud", "hello warn");
+    tracing::error!(target: "loud", "hello error");
+    tracing::error!(target: "quiet", "hello error (quiet)");
+
+    handle.assert_finished();
+}
+
+#[test]
+fn or_accepts_either_filter() {
+    let (expect, handle) = layer::mock()
+        .event(expect::event().at_level(Level::TRACE))
+        .event(expect::event().at_level(Level::WARN))
+        .event(expect::event().at_level(Level::ERROR))
+        .only()
+        .run_with_handle();
+
+    let is_loud = filter_fn(|meta| meta.target().starts_with("loud"));
+
+    let _subscriber = better_tracing::registry()
+        .with(expect.with_filter(LevelFilter::WARN.or(is_loud)))
+        .set_default();
+
+    tracing::trace!(target: "quiet", "hello trace (quiet)");
+    tracing::trace!(target: "loud", "hello trace");
+    tracing::info!(target: "quiet", "hello info (quiet)");
+    tracing::warn!(target: "quiet", "hello warn");
+    tracing::error!(target: "quiet", "hello error");
+
+    handle.assert_finished();
+}
+
+#[test]
+fn not_inverts_the_filter() {
+    let (expect, handle) = layer::mock()
+        .event(expect::event().at_level(Level::TRACE))
+        .event(expect::event().at_level(Level::DEBUG))
+        .only()
+        .run_with_handle();
+
+    let _subscriber = better_tracing::registry()
+        .with(expect.with_filter(LevelFilter::INFO.not()))
+        .set_default();
+
+    tracing::trace!("hello trace");
+    tracing::debug!("hello debug");
+    tracing::info!("hello info");
+    tracing::warn!("hello warn");
+    tracing::error!("hello error");
+
+    handle.assert_finished();
+}
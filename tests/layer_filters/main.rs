@@ -1,5 +1,6 @@
 #![cfg(feature = "registry")]
 mod boxed;
+mod combinators;
 mod downcast_raw;
 mod filter_scopes;
 mod option;